@@ -1,36 +1,44 @@
-use node_template_runtime::{AccountId, RuntimeGenesisConfig, Signature, WASM_BINARY};
-use sc_service::ChainType;
+use crate::spec_builder::GenesisDescription;
+use node_template_runtime::{
+	currency::{TOKEN_DECIMALS, TOKEN_SYMBOL},
+	genesis_config_presets::{
+		self, DEV_RUNTIME_PRESET, LOCAL_TESTNET_RUNTIME_PRESET, STAGING_RUNTIME_PRESET,
+	},
+	opaque::SessionKeys,
+	RuntimeGenesisConfig, SS58_PREFIX, WASM_BINARY,
+};
+use sc_service::{ChainType, Properties};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
-use sp_core::{sr25519, Pair, Public};
-use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_core::{crypto::ByteArray, ed25519, sr25519, Pair};
 
-// The URL for the telemetry server.
-// const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
+/// The keys, boot nodes and telemetry endpoints of the staging network.
+const STAGING_SPEC: &str = include_str!("../res/staging.json");
+
+/// The names of the well-known development accounts, as in `//Alice`.
+const DEV_SEEDS: [&str; 8] = ["Alice", "Bob", "Charlie", "Dave", "Eve", "Ferdie", "One", "Two"];
 
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
 pub type ChainSpec = sc_service::GenericChainSpec<RuntimeGenesisConfig>;
 
-/// Generate a crypto pair from seed.
-pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
-	TPublic::Pair::from_string(&format!("//{}", seed), None)
-		.expect("static values are valid; qed")
-		.public()
+pub fn session_keys(aura: AuraId, grandpa: GrandpaId) -> SessionKeys {
+	SessionKeys { aura, grandpa }
 }
 
-type AccountPublic = <Signature as Verify>::Signer;
-
-/// Generate an account ID from seed.
-pub fn get_account_id_from_seed<TPublic: Public>(seed: &str) -> AccountId
-where
-	AccountPublic: From<<TPublic::Pair as Pair>::Public>,
-{
-	AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
+/// The properties wallets read the token symbol and decimals and the address format from.
+pub fn chain_properties() -> Properties {
+	let mut properties = Properties::new();
+	properties.insert("tokenSymbol".into(), TOKEN_SYMBOL.into());
+	properties.insert("tokenDecimals".into(), TOKEN_DECIMALS.into());
+	properties.insert("ss58Format".into(), SS58_PREFIX.into());
+	properties
 }
 
-/// Generate an Aura authority key.
-pub fn authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
-	(get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
+/// The genesis config patch of the runtime preset `id`.
+fn genesis_preset(id: &str) -> Result<serde_json::Value, String> {
+	let preset = genesis_config_presets::get_preset(id)
+		.ok_or_else(|| format!("Unknown genesis preset `{}`", id))?;
+	serde_json::from_slice(&preset).map_err(|e| format!("Invalid genesis preset `{}`: {}", id, e))
 }
 
 pub fn development_config() -> Result<ChainSpec, String> {
@@ -41,20 +49,8 @@ pub fn development_config() -> Result<ChainSpec, String> {
 	.with_name("Development")
 	.with_id("dev")
 	.with_chain_type(ChainType::Development)
-	.with_genesis_config_patch(testnet_genesis(
-		// Initial PoA authorities
-		vec![authority_keys_from_seed("Alice")],
-		// Sudo account
-		get_account_id_from_seed::<sr25519::Public>("Alice"),
-		// Pre-funded accounts
-		vec![
-			get_account_id_from_seed::<sr25519::Public>("Alice"),
-			get_account_id_from_seed::<sr25519::Public>("Bob"),
-			get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-			get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-		],
-		true,
-	))
+	.with_properties(chain_properties())
+	.with_genesis_config_patch(genesis_preset(DEV_RUNTIME_PRESET)?)
 	.build())
 }
 
@@ -66,52 +62,124 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 	.with_name("Local Testnet")
 	.with_id("local_testnet")
 	.with_chain_type(ChainType::Local)
-	.with_genesis_config_patch(testnet_genesis(
-		// Initial PoA authorities
-		vec![authority_keys_from_seed("Alice"), authority_keys_from_seed("Bob")],
-		// Sudo account
-		get_account_id_from_seed::<sr25519::Public>("Alice"),
-		// Pre-funded accounts
-		vec![
-			get_account_id_from_seed::<sr25519::Public>("Alice"),
-			get_account_id_from_seed::<sr25519::Public>("Bob"),
-			get_account_id_from_seed::<sr25519::Public>("Charlie"),
-			get_account_id_from_seed::<sr25519::Public>("Dave"),
-			get_account_id_from_seed::<sr25519::Public>("Eve"),
-			get_account_id_from_seed::<sr25519::Public>("Ferdie"),
-			get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-			get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-			get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
-			get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
-			get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
-			get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
-		],
-		true,
-	))
+	.with_properties(chain_properties())
+	.with_genesis_config_patch(genesis_preset(LOCAL_TESTNET_RUNTIME_PRESET)?)
 	.build())
 }
 
-/// Configure initial storage state for FRAME modules.
-fn testnet_genesis(
-	initial_authorities: Vec<(AuraId, GrandpaId)>,
-	root_key: AccountId,
-	endowed_accounts: Vec<AccountId>,
-	_enable_println: bool,
-) -> serde_json::Value {
-	serde_json::json!({
-		"balances": {
-			// Configure endowed accounts with initial balance of 1 << 60.
-			"balances": endowed_accounts.iter().cloned().map(|k| (k, 1u64 << 60)).collect::<Vec<_>>(),
-		},
-		"aura": {
-			"authorities": initial_authorities.iter().map(|x| (x.0.clone())).collect::<Vec<_>>(),
-		},
-		"grandpa": {
-			"authorities": initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
-		},
-		"sudo": {
-			// Assign network admin rights.
-			"key": Some(root_key),
-		},
-	})
+/// A live network, with the keys, boot nodes and telemetry endpoints of `res/staging.json` on top
+/// of the runtime's staging preset.
+///
+/// The resource ships without keys or boot nodes: operators fill in those of their network before
+/// launching it. Refuses to build until then, and if the resource uses a secret URI or any
+/// development key, so that a network anyone could take over never gets launched by mistake.
+pub fn staging_config() -> Result<ChainSpec, String> {
+	staging_config_from(STAGING_SPEC)
+}
+
+/// The staging chain spec of the resource `spec`, see [`staging_config`].
+fn staging_config_from(spec: &str) -> Result<ChainSpec, String> {
+	let description: GenesisDescription = serde_json::from_str(spec)
+		.map_err(|e| format!("Invalid staging chain spec resource: {}", e))?;
+
+	if description.sudo.is_empty() || description.authorities.is_empty() {
+		return Err("The staging chain spec has no keys yet: fill in the sudo key, authorities and \
+			endowments of your network in `node/res/staging.json`"
+			.into())
+	}
+	let dev_keys = dev_public_keys();
+	for (key, public) in description.public_keys()? {
+		if key.starts_with('/') {
+			return Err(format!("The staging chain spec must not use secret URIs, found `{}`", key))
+		}
+		if dev_keys.contains(&public) {
+			return Err(format!("The staging chain spec uses the development key `{}`", key))
+		}
+	}
+
+	description.chain_spec(genesis_preset(STAGING_RUNTIME_PRESET)?)
+}
+
+/// The public keys of the development accounts and their `//stash` accounts, for each key type
+/// the chain uses.
+fn dev_public_keys() -> Vec<Vec<u8>> {
+	DEV_SEEDS
+		.iter()
+		.flat_map(|seed| [format!("//{}", seed), format!("//{}//stash", seed)])
+		.flat_map(|uri| {
+			[
+				sr25519::Pair::from_string(&uri, None)
+					.expect("static values are valid; qed")
+					.public()
+					.to_raw_vec(),
+				ed25519::Pair::from_string(&uri, None)
+					.expect("static values are valid; qed")
+					.public()
+					.to_raw_vec(),
+			]
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::crypto::Ss58Codec;
+
+	/// A staging chain spec resource with a single authority, whose account is also the sudo key.
+	fn spec(account: &str, aura: &str, grandpa: &str) -> String {
+		serde_json::json!({
+			"name": "Staging Testnet",
+			"id": "staging_testnet",
+			"chain_type": "live",
+			"sudo": account,
+			"authorities": [{ "account": account, "aura": aura, "grandpa": grandpa }],
+			"endowed": [{ "account": account, "balance": 1_000_000_000_000_000u64 }],
+		})
+		.to_string()
+	}
+
+	/// Keys that are not derived from any development seed.
+	fn operator_keys() -> (String, String, String) {
+		let sr = sr25519::Pair::from_seed(&[1; 32]).public();
+		let ed = ed25519::Pair::from_seed(&[1; 32]).public();
+		(sr.to_ss58check(), sr.to_ss58check(), sp_core::bytes::to_hex(ed.as_slice(), false))
+	}
+
+	fn rejection(spec: &str) -> String {
+		match staging_config_from(spec) {
+			Ok(_) => panic!("the staging chain spec was accepted"),
+			Err(e) => e,
+		}
+	}
+
+	#[test]
+	fn shipped_resource_must_be_filled_in() {
+		assert!(rejection(STAGING_SPEC).contains("no keys yet"));
+	}
+
+	#[test]
+	fn secret_uris_are_rejected() {
+		let (_, aura, grandpa) = operator_keys();
+		assert!(rejection(&spec("//Alice", &aura, &grandpa)).contains("secret URIs"));
+	}
+
+	#[test]
+	fn development_addresses_are_rejected() {
+		let (_, aura, grandpa) = operator_keys();
+		for seed in ["//Alice", "//Bob//stash"] {
+			let account = sr25519::Pair::from_string(seed, None).unwrap().public().to_ss58check();
+			let e = rejection(&spec(&account, &aura, &grandpa));
+			assert!(e.contains("development key"), "{}: {}", seed, e);
+		}
+	}
+
+	#[test]
+	fn development_hex_keys_are_rejected() {
+		let (account, aura, _) = operator_keys();
+		let grandpa = ed25519::Pair::from_string("//Alice", None).unwrap().public();
+		let e =
+			rejection(&spec(&account, &aura, &sp_core::bytes::to_hex(grandpa.as_slice(), false)));
+		assert!(e.contains("development key"), "{}", e);
+	}
 }
@@ -1,4 +1,38 @@
 use sc_cli::RunCmd;
+use std::str::FromStr;
+
+/// Block authoring modes that replace Aura when running a development node.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sealing {
+	/// Seal a block only when requested through the `engine_createBlock` RPC.
+	Manual,
+	/// Seal a block as soon as a transaction enters the pool.
+	Instant,
+	/// Seal a block every given number of milliseconds.
+	Interval(u64),
+}
+
+impl FromStr for Sealing {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"manual" => Ok(Self::Manual),
+			"instant" => Ok(Self::Instant),
+			_ => match s.strip_prefix("interval=") {
+				Some(millis) => match millis.parse() {
+					Ok(0) => Err("the sealing interval must be at least one millisecond".into()),
+					Ok(millis) => Ok(Self::Interval(millis)),
+					Err(e) => Err(format!("invalid sealing interval `{}`: {}", millis, e)),
+				},
+				None => Err(format!(
+					"invalid sealing mode `{}`, expected `manual`, `instant` or `interval=<ms>`",
+					s
+				)),
+			},
+		}
+	}
+}
 
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
@@ -7,6 +41,19 @@ pub struct Cli {
 
 	#[clap(flatten)]
 	pub run: RunCmd,
+
+	/// Replace Aura and GRANDPA with a manual-seal engine.
+	///
+	/// One of `manual`, `instant` or `interval=<ms>`. In every mode blocks can also be
+	/// created and finalized on demand through the `engine_createBlock` and
+	/// `engine_finalizeBlock` RPCs. Only authoring nodes seal blocks, so this requires
+	/// `--validator` or `--dev`.
+	#[arg(long, value_name = "MODE")]
+	pub sealing: Option<Sealing>,
+
+	#[cfg(feature = "evm")]
+	#[command(flatten)]
+	pub eth: crate::eth::EthConfiguration,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -19,6 +66,9 @@ pub enum Subcommand {
 	/// Build a chain specification.
 	BuildSpec(sc_cli::BuildSpecCmd),
 
+	/// Generate plain and raw chain specifications from a TOML or YAML genesis description.
+	GenerateSpec(crate::spec_builder::GenerateSpecCmd),
+
 	/// Validate blocks.
 	CheckBlock(sc_cli::CheckBlockCmd),
 
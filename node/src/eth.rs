@@ -0,0 +1,159 @@
+//! Frontier, the Ethereum compatibility layer of the node, compiled in with the `evm` feature.
+//!
+//! Frontier keeps a database of its own next to the chain database, mapping Ethereum block and
+//! transaction hashes to the blocks of the chain. The mapping-sync worker fills it as blocks are
+//! imported, and the `eth_*` RPC methods read from it.
+
+use crate::service::{FullBackend, FullClient};
+use fc_rpc::{EthBlockDataCacheTask, EthTask, OverrideHandle};
+use fc_rpc_core::types::{FeeHistoryCache, FeeHistoryCacheLimit, FilterPool};
+use futures::StreamExt;
+use node_template_runtime::opaque::Block;
+use sc_client_api::BlockchainEvents;
+use sc_network_sync::SyncingService;
+use sc_service::{error::Error as ServiceError, Configuration, TaskManager};
+use std::{
+	collections::BTreeMap,
+	path::PathBuf,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+/// The number of blocks after which a filter that was not polled is removed.
+const FILTER_RETAIN_THRESHOLD: u64 = 100;
+
+/// Options of the Ethereum RPC.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct EthConfiguration {
+	/// The maximum number of logs returned by a single `eth_getLogs` query.
+	#[arg(long, default_value = "10000")]
+	pub max_past_logs: u32,
+
+	/// The maximum number of blocks `eth_feeHistory` reports on.
+	#[arg(long, default_value = "2048")]
+	pub fee_history_limit: u64,
+
+	/// The maximum number of filters installed through `eth_newFilter` and the like at once.
+	#[arg(long, default_value = "500")]
+	pub max_stored_filters: usize,
+
+	/// The gas limit of `eth_call` and `eth_estimateGas`, as a multiple of the block gas limit.
+	#[arg(long, default_value = "10")]
+	pub execute_gas_limit_multiplier: u64,
+
+	/// The size in bytes of the cache of Ethereum blocks.
+	#[arg(long, default_value = "50")]
+	pub eth_log_block_cache: usize,
+
+	/// The size in bytes of the cache of Ethereum transaction statuses.
+	#[arg(long, default_value = "50")]
+	pub eth_statuses_cache: usize,
+
+	/// Sign `eth_sendTransaction` calls with the keys of the Ethereum development accounts.
+	#[arg(long)]
+	pub enable_dev_signer: bool,
+}
+
+/// The Frontier state shared by the mapping-sync worker, the maintenance tasks and the RPC.
+#[derive(Clone)]
+pub struct FrontierComponents {
+	/// The database mapping Ethereum hashes to blocks.
+	pub backend: Arc<fc_db::kv::Backend<Block>>,
+	/// Reads the Ethereum state of blocks from runtime storage.
+	pub overrides: Arc<OverrideHandle<Block>>,
+	/// The filters installed through the RPC.
+	pub filter_pool: FilterPool,
+	/// The fee data of the latest blocks, for `eth_feeHistory`.
+	pub fee_history_cache: FeeHistoryCache,
+	/// The number of blocks kept in `fee_history_cache`.
+	pub fee_history_cache_limit: FeeHistoryCacheLimit,
+	/// Caches Ethereum blocks and transaction statuses.
+	pub block_data_cache: Arc<EthBlockDataCacheTask<Block>>,
+	/// The subscribers to new Ethereum blocks, notified by the mapping-sync worker.
+	pub pubsub_notification_sinks: Arc<
+		fc_mapping_sync::EthereumBlockNotificationSinks<
+			fc_mapping_sync::EthereumBlockNotification<Block>,
+		>,
+	>,
+}
+
+/// Open the Frontier database of the chain and spawn the tasks keeping it and the caches of the
+/// RPC up to date.
+pub fn spawn_frontier_tasks(
+	config: &Configuration,
+	eth_config: &EthConfiguration,
+	client: Arc<FullClient>,
+	backend: Arc<FullBackend>,
+	task_manager: &TaskManager,
+	sync_service: Arc<SyncingService<Block>>,
+) -> Result<FrontierComponents, ServiceError> {
+	let frontier_backend = Arc::new(
+		fc_db::kv::Backend::open(client.clone(), &config.database, &db_config_dir(config))
+			.map_err(|e| {
+				ServiceError::Other(format!("Failed to open the Frontier database: {}", e))
+			})?,
+	);
+	let overrides = fc_rpc::overrides_handle(client.clone());
+	let filter_pool: FilterPool = Arc::new(Mutex::new(BTreeMap::new()));
+	let fee_history_cache: FeeHistoryCache = Arc::new(Mutex::new(BTreeMap::new()));
+	let pubsub_notification_sinks = Arc::new(Default::default());
+
+	task_manager.spawn_essential_handle().spawn(
+		"frontier-mapping-sync-worker",
+		Some("frontier"),
+		fc_mapping_sync::kv::MappingSyncWorker::new(
+			client.import_notification_stream(),
+			Duration::new(6, 0),
+			client.clone(),
+			backend,
+			overrides.clone(),
+			frontier_backend.clone(),
+			3,
+			0,
+			fc_mapping_sync::SyncStrategy::Normal,
+			sync_service,
+			pubsub_notification_sinks.clone(),
+		)
+		.for_each(|()| futures::future::ready(())),
+	);
+
+	task_manager.spawn_essential_handle().spawn(
+		"frontier-filter-pool",
+		Some("frontier"),
+		EthTask::filter_pool_task(client.clone(), filter_pool.clone(), FILTER_RETAIN_THRESHOLD),
+	);
+
+	task_manager.spawn_essential_handle().spawn(
+		"frontier-fee-history",
+		Some("frontier"),
+		EthTask::fee_history_task(
+			client,
+			overrides.clone(),
+			fee_history_cache.clone(),
+			eth_config.fee_history_limit,
+		),
+	);
+
+	let block_data_cache = Arc::new(EthBlockDataCacheTask::new(
+		task_manager.spawn_handle(),
+		overrides.clone(),
+		eth_config.eth_log_block_cache,
+		eth_config.eth_statuses_cache,
+		config.prometheus_registry().cloned(),
+	));
+
+	Ok(FrontierComponents {
+		backend: frontier_backend,
+		overrides,
+		filter_pool,
+		fee_history_cache,
+		fee_history_cache_limit: eth_config.fee_history_limit,
+		block_data_cache,
+		pubsub_notification_sinks,
+	})
+}
+
+/// The directory of the Frontier database, next to the keystore and network keys of the chain.
+fn db_config_dir(config: &Configuration) -> PathBuf {
+	config.base_path.config_dir(config.chain_spec.id())
+}
@@ -5,8 +5,11 @@ mod benchmarking;
 mod chain_spec;
 mod cli;
 mod command;
+#[cfg(feature = "evm")]
+mod eth;
 mod rpc;
 mod service;
+mod spec_builder;
 
 fn main() -> sc_cli::Result<()> {
 	command::run()
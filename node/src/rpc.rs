@@ -5,10 +5,18 @@
 
 #![warn(missing_docs)]
 
+pub mod contracts;
+#[cfg(feature = "evm")]
+pub mod eth;
+
 use std::sync::Arc;
 
+use futures::channel::mpsc;
 use jsonrpsee::RpcModule;
-use node_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use node_template_runtime::{
+	opaque::Block, AccountId, Balance, BlockNumber, EventRecord, Hash, Nonce,
+};
+use sc_consensus_manual_seal::EngineCommand;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
@@ -24,6 +32,11 @@ pub struct FullDeps<C, P> {
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// Channel for sending commands to the manual-seal engine, if the node runs one.
+	pub command_sink: Option<mpsc::Sender<EngineCommand<Hash>>>,
+	/// Dependencies of the Ethereum RPC.
+	#[cfg(feature = "evm")]
+	pub eth: eth::EthDeps,
 }
 
 /// Instantiate all full RPC extensions.
@@ -36,17 +49,42 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_template_rpc::TemplateRuntimeApi<Block, AccountId>,
+	C::Api:
+		contracts::ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash, EventRecord>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
+	use contracts::{Contracts, ContractsApiServer};
+	use pallet_template_rpc::{Template, TemplateApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	let mut module = RpcModule::new(());
-	let FullDeps { client, pool, deny_unsafe } = deps;
+	let FullDeps {
+		client,
+		pool,
+		deny_unsafe,
+		command_sink,
+		#[cfg(feature = "evm")]
+		eth,
+	} = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Template::new(client.clone()).into_rpc())?;
+	// `contracts_call`, `contracts_instantiate` and `contracts_getStorage`.
+	module.merge(Contracts::new(client).into_rpc())?;
+
+	if let Some(command_sink) = command_sink {
+		// `engine_createBlock` and `engine_finalizeBlock`.
+		module.merge(ManualSeal::new(command_sink).into_rpc())?;
+	}
+
+	// `eth_*`, `net_*` and `web3_*`.
+	#[cfg(feature = "evm")]
+	module.merge(eth::create_eth(eth)?)?;
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
@@ -0,0 +1,316 @@
+//! RPC methods to dry-run contract calls and instantiations and to read contract storage.
+//!
+//! The methods are backed by the [`ContractsRuntimeApi`] of `pallet_contracts` and execute at the
+//! best block unless a block hash is given. Nothing they do is persisted, which makes them the way
+//! for clients to estimate the gas and storage deposit limits of a transaction.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use node_template_runtime::{
+	opaque::Block, AccountId, Balance, BlockNumber, EventRecord, Hash, Weight,
+};
+use pallet_contracts::{
+	Code, ContractExecResult, ContractInstantiateResult, ContractResult, ExecReturnValue,
+	StorageDeposit,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+
+pub use pallet_contracts::ContractsApi as ContractsRuntimeApi;
+
+/// A contract call to dry-run.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CallRequest {
+	/// The account calling the contract.
+	pub origin: AccountId,
+	/// The contract to call.
+	pub dest: AccountId,
+	/// The balance transferred to the contract.
+	#[serde(default)]
+	pub value: NumberOrHex,
+	/// The gas limit of the call, or the maximum weight of a block if `None`.
+	pub gas_limit: Option<Weight>,
+	/// The storage deposit limit of the call, or no limit if `None`.
+	pub storage_deposit_limit: Option<NumberOrHex>,
+	/// The input of the call, e.g. an ink! message selector followed by its arguments.
+	pub input_data: Bytes,
+}
+
+/// The code of a contract to instantiate.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CodeRequest {
+	/// Upload the wasm blob and instantiate it.
+	Upload(Bytes),
+	/// Instantiate code already uploaded under the hash.
+	Existing(Hash),
+}
+
+/// A contract instantiation to dry-run.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct InstantiateRequest {
+	/// The account instantiating the contract.
+	pub origin: AccountId,
+	/// The balance transferred to the new contract.
+	#[serde(default)]
+	pub value: NumberOrHex,
+	/// The gas limit of the instantiation, or the maximum weight of a block if `None`.
+	pub gas_limit: Option<Weight>,
+	/// The storage deposit limit of the instantiation, or no limit if `None`.
+	pub storage_deposit_limit: Option<NumberOrHex>,
+	/// The code of the contract.
+	pub code: CodeRequest,
+	/// The input of the constructor.
+	pub data: Bytes,
+	/// The salt the address of the contract is derived with.
+	#[serde(default)]
+	pub salt: Bytes,
+}
+
+/// The storage deposit of a dry-run.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StorageDepositResult {
+	/// The origin is refunded the balance.
+	Refund(NumberOrHex),
+	/// The origin is charged the balance.
+	Charge(NumberOrHex),
+}
+
+/// The value returned by a contract.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnValue {
+	/// Whether the contract reverted its changes.
+	pub reverted: bool,
+	/// The output of the contract.
+	pub data: Bytes,
+}
+
+/// The result of a dry-run instantiation.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstantiateReturnValue {
+	/// The value returned by the constructor.
+	#[serde(flatten)]
+	pub result: ReturnValue,
+	/// The address of the new contract.
+	pub account_id: AccountId,
+}
+
+/// The outcome of a dry-run call or instantiation.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecResult<R> {
+	/// The weight consumed by the execution.
+	pub gas_consumed: Weight,
+	/// The gas limit needed for the execution to succeed, which can exceed `gas_consumed`.
+	pub gas_required: Weight,
+	/// The storage deposit limit needed for the execution to succeed.
+	pub storage_deposit: StorageDepositResult,
+	/// The messages printed by the contract through `debug_message`.
+	pub debug_message: String,
+	/// The value returned by the contract, or the error the execution failed with.
+	pub result: Result<R, String>,
+}
+
+impl<T, R> From<ContractResult<Result<T, sp_runtime::DispatchError>, Balance, EventRecord>>
+	for ExecResult<R>
+where
+	T: Into<R>,
+{
+	fn from(
+		result: ContractResult<Result<T, sp_runtime::DispatchError>, Balance, EventRecord>,
+	) -> Self {
+		ExecResult {
+			gas_consumed: result.gas_consumed,
+			gas_required: result.gas_required,
+			storage_deposit: match result.storage_deposit {
+				StorageDeposit::Refund(balance) => StorageDepositResult::Refund(balance.into()),
+				StorageDeposit::Charge(balance) => StorageDepositResult::Charge(balance.into()),
+			},
+			debug_message: String::from_utf8_lossy(&result.debug_message).into_owned(),
+			result: result.result.map(Into::into).map_err(|e| format!("{:?}", e)),
+		}
+	}
+}
+
+impl From<ExecReturnValue> for ReturnValue {
+	fn from(value: ExecReturnValue) -> Self {
+		ReturnValue { reverted: value.did_revert(), data: value.data.into() }
+	}
+}
+
+impl From<pallet_contracts::InstantiateReturnValue<AccountId>> for InstantiateReturnValue {
+	fn from(value: pallet_contracts::InstantiateReturnValue<AccountId>) -> Self {
+		InstantiateReturnValue { result: value.result.into(), account_id: value.account_id }
+	}
+}
+
+/// Contracts RPC methods.
+#[rpc(server)]
+pub trait ContractsApi<BlockHash> {
+	/// Dry-run a call to a contract.
+	#[method(name = "contracts_call")]
+	fn call(
+		&self,
+		request: CallRequest,
+		at: Option<BlockHash>,
+	) -> RpcResult<ExecResult<ReturnValue>>;
+
+	/// Dry-run the instantiation of a contract.
+	#[method(name = "contracts_instantiate")]
+	fn instantiate(
+		&self,
+		request: InstantiateRequest,
+		at: Option<BlockHash>,
+	) -> RpcResult<ExecResult<InstantiateReturnValue>>;
+
+	/// Read the value a contract stores under `key`, or `None` if there is none.
+	#[method(name = "contracts_getStorage")]
+	fn get_storage(
+		&self,
+		address: AccountId,
+		key: Bytes,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Bytes>>;
+}
+
+/// Provides RPC methods to dry-run contracts.
+pub struct Contracts<C> {
+	client: Arc<C>,
+}
+
+impl<C> Contracts<C> {
+	/// Creates a new instance of the Contracts RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// A balance of the request does not fit into a `Balance`.
+	InvalidBalance,
+	/// The address is not a contract.
+	ContractAccessError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidBalance => 2,
+			Error::ContractAccessError => 3,
+		}
+	}
+}
+
+fn runtime_error(e: impl ToString) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to call the runtime.",
+		Some(e.to_string()),
+	)
+}
+
+fn balance(value: NumberOrHex) -> RpcResult<Balance> {
+	value.try_into().map_err(|_| {
+		ErrorObject::owned(
+			Error::InvalidBalance.into(),
+			"Balance out of range.",
+			Some(format!("{:?}", value)),
+		)
+	})
+}
+
+impl<C> ContractsApiServer<Hash> for Contracts<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash, EventRecord>,
+{
+	fn call(&self, request: CallRequest, at: Option<Hash>) -> RpcResult<ExecResult<ReturnValue>> {
+		let CallRequest { origin, dest, value, gas_limit, storage_deposit_limit, input_data } =
+			request;
+		let result: ContractExecResult<Balance, EventRecord> = self
+			.client
+			.runtime_api()
+			.call(
+				at.unwrap_or_else(|| self.client.info().best_hash),
+				origin,
+				dest,
+				balance(value)?,
+				gas_limit,
+				storage_deposit_limit.map(balance).transpose()?,
+				input_data.to_vec(),
+			)
+			.map_err(runtime_error)?;
+		Ok(result.into())
+	}
+
+	fn instantiate(
+		&self,
+		request: InstantiateRequest,
+		at: Option<Hash>,
+	) -> RpcResult<ExecResult<InstantiateReturnValue>> {
+		let InstantiateRequest {
+			origin,
+			value,
+			gas_limit,
+			storage_deposit_limit,
+			code,
+			data,
+			salt,
+		} = request;
+		let code = match code {
+			CodeRequest::Upload(code) => Code::Upload(code.to_vec()),
+			CodeRequest::Existing(hash) => Code::Existing(hash),
+		};
+		let result: ContractInstantiateResult<AccountId, Balance, EventRecord> = self
+			.client
+			.runtime_api()
+			.instantiate(
+				at.unwrap_or_else(|| self.client.info().best_hash),
+				origin,
+				balance(value)?,
+				gas_limit,
+				storage_deposit_limit.map(balance).transpose()?,
+				code,
+				data.to_vec(),
+				salt.to_vec(),
+			)
+			.map_err(runtime_error)?;
+		Ok(result.into())
+	}
+
+	fn get_storage(
+		&self,
+		address: AccountId,
+		key: Bytes,
+		at: Option<Hash>,
+	) -> RpcResult<Option<Bytes>> {
+		self.client
+			.runtime_api()
+			.get_storage(at.unwrap_or_else(|| self.client.info().best_hash), address, key.to_vec())
+			.map_err(runtime_error)?
+			.map(|value| value.map(Into::into))
+			.map_err(|e| {
+				ErrorObject::owned(
+					Error::ContractAccessError.into(),
+					"Unable to read the contract storage.",
+					Some(format!("{:?}", e)),
+				)
+			})
+	}
+}
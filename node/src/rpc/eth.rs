@@ -0,0 +1,133 @@
+//! The Ethereum RPC methods, `eth_*`, `net_*` and `web3_*`, compiled in with the `evm` feature.
+
+use std::sync::Arc;
+
+use crate::{
+	eth::{EthConfiguration, FrontierComponents},
+	service::FullClient,
+};
+use fc_rpc::{
+	pending::AuraConsensusDataProvider, Eth, EthApiServer, EthDevSigner, EthFilter,
+	EthFilterApiServer, EthPubSub, EthPubSubApiServer, EthSigner, Net, NetApiServer, Web3,
+	Web3ApiServer,
+};
+use jsonrpsee::RpcModule;
+use node_template_runtime::{evm::TransactionConverter, opaque::Block, Hash};
+use sc_network_sync::SyncingService;
+use sc_rpc::SubscriptionTaskExecutor;
+use sc_transaction_pool::{FullChainApi, FullPool, Pool};
+use sp_consensus_aura::SlotDuration;
+
+/// Dependencies of the Ethereum RPC.
+pub struct EthDeps {
+	/// The client instance to use.
+	pub client: Arc<FullClient>,
+	/// Transaction pool instance.
+	pub pool: Arc<FullPool<Block, FullClient>>,
+	/// The graph of the transaction pool, which holds the pending transactions.
+	pub graph: Arc<Pool<FullChainApi<FullClient, Block>>>,
+	/// The network service, for `net_peerCount`.
+	pub network: Arc<sc_network::NetworkService<Block, Hash>>,
+	/// The syncing service, for `eth_syncing`.
+	pub sync: Arc<SyncingService<Block>>,
+	/// Whether the node authors blocks, for `eth_mining`.
+	pub is_authority: bool,
+	/// The Frontier database, caches and subscribers.
+	pub frontier: FrontierComponents,
+	/// The options of the Ethereum RPC.
+	pub config: EthConfiguration,
+	/// The Aura slot duration, to build pending blocks with.
+	pub slot_duration: SlotDuration,
+	/// Runs the tasks of `eth_subscribe` subscriptions.
+	pub subscription_task_executor: SubscriptionTaskExecutor,
+}
+
+/// Instantiate the Ethereum RPC extensions.
+pub fn create_eth(
+	deps: EthDeps,
+) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>> {
+	let mut module = RpcModule::new(());
+	let EthDeps {
+		client,
+		pool,
+		graph,
+		network,
+		sync,
+		is_authority,
+		frontier,
+		config,
+		slot_duration,
+		subscription_task_executor,
+	} = deps;
+
+	let mut signers = Vec::<Box<dyn EthSigner>>::new();
+	if config.enable_dev_signer {
+		signers.push(Box::new(EthDevSigner::new()));
+	}
+
+	// Pending blocks are built on top of the best block, in the next slot.
+	let pending_create_inherent_data_providers = move |_, ()| async move {
+		let current = sp_timestamp::InherentDataProvider::from_system_time();
+		let next_slot = current.timestamp().as_millis() + slot_duration.as_millis();
+		let timestamp = sp_timestamp::InherentDataProvider::new(next_slot.into());
+		let slot =
+			sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+				*timestamp,
+				slot_duration,
+			);
+		Ok((slot, timestamp))
+	};
+
+	module.merge(
+		Eth::<Block, _, _, _, _, _, _, ()>::new(
+			client.clone(),
+			pool.clone(),
+			graph.clone(),
+			Some(TransactionConverter::<Block>::default()),
+			sync.clone(),
+			signers,
+			frontier.overrides.clone(),
+			frontier.backend.clone(),
+			is_authority,
+			frontier.block_data_cache.clone(),
+			frontier.fee_history_cache,
+			frontier.fee_history_cache_limit,
+			config.execute_gas_limit_multiplier,
+			None,
+			pending_create_inherent_data_providers,
+			Some(Box::new(AuraConsensusDataProvider::new(client.clone()))),
+		)
+		.into_rpc(),
+	)?;
+
+	module.merge(
+		EthFilter::new(
+			client.clone(),
+			frontier.backend,
+			graph,
+			frontier.filter_pool,
+			config.max_stored_filters,
+			config.max_past_logs,
+			frontier.block_data_cache,
+		)
+		.into_rpc(),
+	)?;
+
+	module.merge(
+		EthPubSub::new(
+			pool,
+			client.clone(),
+			sync,
+			subscription_task_executor,
+			frontier.overrides,
+			frontier.pubsub_notification_sinks,
+		)
+		.into_rpc(),
+	)?;
+
+	// Report the peer count as a hex string, as Ethereum clients expect.
+	module.merge(Net::new(client.clone(), network, true).into_rpc())?;
+	module.merge(Web3::new(client).into_rpc())?;
+
+	Ok(module)
+}
@@ -1,14 +1,22 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
-use futures::FutureExt;
-use node_template_runtime::{self, opaque::Block, RuntimeApi};
+use crate::cli::Sealing;
+use futures::{stream::BoxStream, FutureExt, StreamExt};
+use node_template_runtime::{self, opaque::Block, Hash, RuntimeApi};
 use sc_client_api::{Backend, BlockBackend};
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 use sc_consensus_grandpa::SharedVoterState;
-use sc_service::{error::Error as ServiceError, Configuration, TaskManager, WarpSyncParams};
+use sc_consensus_manual_seal::{
+	consensus::aura::AuraConsensusDataProvider, EngineCommand, ManualSealParams,
+};
+use sc_service::{
+	error::Error as ServiceError, ChainType, Configuration, TaskManager, WarpSyncParams,
+};
 use sc_telemetry::{Telemetry, TelemetryWorker};
-use sc_transaction_pool_api::OffchainTransactionPoolFactory;
-use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
+use sc_transaction_pool_api::{OffchainTransactionPoolFactory, TransactionPool};
+use sp_blockchain::HeaderBackend;
+use sp_consensus_aura::sr25519::{AuthorityPair as AuraPair, AuthoritySignature as AuraSignature};
+use sp_keystore::Keystore;
 use std::{sync::Arc, time::Duration};
 
 pub(crate) type FullClient = sc_service::TFullClient<
@@ -16,7 +24,7 @@ pub(crate) type FullClient = sc_service::TFullClient<
 	RuntimeApi,
 	sc_executor::WasmExecutor<sp_io::SubstrateHostFunctions>,
 >;
-type FullBackend = sc_service::TFullBackend<Block>;
+pub(crate) type FullBackend = sc_service::TFullBackend<Block>;
 type FullSelectChain = sc_consensus::LongestChain<FullBackend, Block>;
 
 /// The minimum period of blocks on which justifications will be
@@ -36,7 +44,10 @@ pub type Service = sc_service::PartialComponents<
 	),
 >;
 
-pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
+pub fn new_partial(
+	config: &Configuration,
+	sealing: Option<Sealing>,
+) -> Result<Service, ServiceError> {
 	let telemetry = config
 		.telemetry_endpoints
 		.clone()
@@ -80,6 +91,26 @@ pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
 		telemetry.as_ref().map(|x| x.handle()),
 	)?;
 
+	// Manually sealed blocks carry no Aura seal, so they must bypass the Aura verifier.
+	if sealing.is_some() {
+		let import_queue = sc_consensus_manual_seal::import_queue(
+			Box::new(grandpa_block_import.clone()),
+			&task_manager.spawn_essential_handle(),
+			config.prometheus_registry(),
+		);
+
+		return Ok(sc_service::PartialComponents {
+			client,
+			backend,
+			task_manager,
+			import_queue,
+			keystore_container,
+			select_chain,
+			transaction_pool,
+			other: (grandpa_block_import, grandpa_link, telemetry),
+		});
+	}
+
 	let cidp_client = client.clone();
 	let import_queue =
 		sc_consensus_aura::import_queue::<AuraPair, _, _, _, _, _>(ImportQueueParams {
@@ -124,7 +155,23 @@ pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
 }
 
 /// Builds a new service for a full client.
-pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
+///
+/// When `sealing` is set, blocks are authored by a manual-seal engine instead of Aura and
+/// GRANDPA is not started; finality is then driven through the `engine_finalizeBlock` RPC.
+///
+/// With the `evm` feature, the node also maintains the Frontier database and serves the Ethereum
+/// RPC configured by `eth_config`.
+pub fn new_full(
+	config: Configuration,
+	sealing: Option<Sealing>,
+	#[cfg(feature = "evm")] eth_config: crate::eth::EthConfiguration,
+) -> Result<TaskManager, ServiceError> {
+	if sealing.is_some() && !config.role.is_authority() {
+		return Err(ServiceError::Other(
+			"`--sealing` requires an authoring node, run with `--validator` or `--dev`".into(),
+		))
+	}
+
 	let sc_service::PartialComponents {
 		client,
 		backend,
@@ -134,7 +181,7 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
 		select_chain,
 		transaction_pool,
 		other: (block_import, grandpa_link, mut telemetry),
-	} = new_partial(&config)?;
+	} = new_partial(&config, sealing)?;
 
 	let mut net_config = sc_network::config::FullNetworkConfiguration::new(&config.network);
 
@@ -165,7 +212,31 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
 			block_relay: None,
 		})?;
 
+	#[cfg(feature = "evm")]
+	let frontier = crate::eth::spawn_frontier_tasks(
+		&config,
+		&eth_config,
+		client.clone(),
+		backend.clone(),
+		&task_manager,
+		sync_service.clone(),
+	)?;
+
 	if config.offchain_worker.enabled {
+		// Let the offchain worker of `pallet_template` sign with Alice's endowed account on
+		// development chains. Other nodes need a key inserted through `author_insertKey`.
+		if config.chain_spec.chain_type() == ChainType::Development {
+			keystore_container
+				.keystore()
+				.sr25519_generate_new(
+					node_template_runtime::pallet_template::KEY_TYPE,
+					Some("//Alice"),
+				)
+				.map_err(|e| {
+					ServiceError::Other(format!("Failed to insert the offchain worker key: {}", e))
+				})?;
+		}
+
 		task_manager.spawn_handle().spawn(
 			"offchain-workers-runner",
 			"offchain-worker",
@@ -190,16 +261,48 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
 	let force_authoring = config.force_authoring;
 	let backoff_authoring_blocks: Option<()> = None;
 	let name = config.network.node_name.clone();
-	let enable_grandpa = !config.disable_grandpa;
+	let enable_grandpa = !config.disable_grandpa && sealing.is_none();
 	let prometheus_registry = config.prometheus_registry().cloned();
 
+	// Channel used by the `engine_*` RPCs to drive the manual-seal authoring task.
+	let (command_sink, commands_stream) = futures::channel::mpsc::channel(1024);
+
 	let rpc_extensions_builder = {
 		let client = client.clone();
 		let pool = transaction_pool.clone();
+		let command_sink = sealing.map(|_| command_sink);
+
+		#[cfg(feature = "evm")]
+		let eth_deps = {
+			let client = client.clone();
+			let pool = transaction_pool.clone();
+			let network = network.clone();
+			let sync = sync_service.clone();
+			let is_authority = config.role.is_authority();
+			let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
+			move |subscription_task_executor| crate::rpc::eth::EthDeps {
+				client: client.clone(),
+				graph: pool.pool().clone(),
+				pool: pool.clone(),
+				network: network.clone(),
+				sync: sync.clone(),
+				is_authority,
+				frontier: frontier.clone(),
+				config: eth_config.clone(),
+				slot_duration,
+				subscription_task_executor,
+			}
+		};
 
-		Box::new(move |deny_unsafe, _| {
-			let deps =
-				crate::rpc::FullDeps { client: client.clone(), pool: pool.clone(), deny_unsafe };
+		Box::new(move |deny_unsafe, _subscription_task_executor| {
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				deny_unsafe,
+				command_sink: command_sink.clone(),
+				#[cfg(feature = "evm")]
+				eth: eth_deps(_subscription_task_executor),
+			};
 			crate::rpc::create_full(deps).map_err(Into::into)
 		})
 	};
@@ -230,41 +333,108 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
 
 		let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
 
-		let aura = sc_consensus_aura::start_aura::<AuraPair, _, _, _, _, _, _, _, _, _, _>(
-			StartAuraParams {
-				slot_duration,
-				client,
-				select_chain,
+		if let Some(sealing) = sealing {
+			let sealing_stream: BoxStream<'static, EngineCommand<Hash>> = match sealing {
+				Sealing::Manual => futures::stream::empty().boxed(),
+				Sealing::Instant => transaction_pool
+					.import_notification_stream()
+					.map(|_| EngineCommand::SealNewBlock {
+						create_empty: false,
+						finalize: false,
+						parent_hash: None,
+						sender: None,
+					})
+					.boxed(),
+				Sealing::Interval(millis) => futures::stream::unfold((), move |()| async move {
+					futures_timer::Delay::new(Duration::from_millis(millis)).await;
+					let command = EngineCommand::SealNewBlock {
+						create_empty: true,
+						finalize: false,
+						parent_hash: None,
+						sender: None,
+					};
+					Some((command, ()))
+				})
+				.boxed(),
+			};
+
+			let cidp_client = client.clone();
+			let manual_seal = sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
 				block_import,
-				proposer_factory,
-				create_inherent_data_providers: move |_, ()| async move {
-					let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
-
-					let slot =
-						sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
-							*timestamp,
-							slot_duration,
-						);
-
-					Ok((slot, timestamp))
+				env: proposer_factory,
+				client: client.clone(),
+				pool: transaction_pool.clone(),
+				commands_stream: futures::stream::select(commands_stream, sealing_stream),
+				select_chain,
+				consensus_data_provider: Some(Box::new(AuraConsensusDataProvider::new(
+					client.clone(),
+				))),
+				create_inherent_data_providers: move |parent_hash, ()| {
+					let cidp_client = cidp_client.clone();
+					async move {
+						// Blocks may be sealed faster than the slot duration, so move the
+						// timestamp forward far enough for every block to claim a new Aura slot.
+						let parent_slot = match cidp_client.header(parent_hash)? {
+							Some(header) => *sc_consensus_aura::find_pre_digest::<
+								Block,
+								AuraSignature,
+							>(&header)?,
+							None => 0,
+						};
+						let timestamp = (*sp_timestamp::Timestamp::current())
+							.max((parent_slot + 1) * slot_duration.as_millis());
+
+						Ok(sp_timestamp::InherentDataProvider::new(timestamp.into()))
+					}
 				},
-				force_authoring,
-				backoff_authoring_blocks,
-				keystore: keystore_container.keystore(),
-				sync_oracle: sync_service.clone(),
-				justification_sync_link: sync_service.clone(),
-				block_proposal_slot_portion: SlotProportion::new(2f32 / 3f32),
-				max_block_proposal_slot_portion: None,
-				telemetry: telemetry.as_ref().map(|x| x.handle()),
-				compatibility_mode: Default::default(),
-			},
-		)?;
-
-		// the AURA authoring task is considered essential, i.e. if it
-		// fails we take down the service with it.
-		task_manager
-			.spawn_essential_handle()
-			.spawn_blocking("aura", Some("block-authoring"), aura);
+			});
+
+			// the manual-seal authoring task is considered essential, i.e. if it
+			// fails we take down the service with it.
+			task_manager.spawn_essential_handle().spawn_blocking(
+				"manual-seal",
+				Some("block-authoring"),
+				manual_seal,
+			);
+		} else {
+			let aura = sc_consensus_aura::start_aura::<AuraPair, _, _, _, _, _, _, _, _, _, _>(
+				StartAuraParams {
+					slot_duration,
+					client,
+					select_chain,
+					block_import,
+					proposer_factory,
+					create_inherent_data_providers: move |_, ()| async move {
+						let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+
+						let slot =
+							sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+								*timestamp,
+								slot_duration,
+							);
+
+						Ok((slot, timestamp))
+					},
+					force_authoring,
+					backoff_authoring_blocks,
+					keystore: keystore_container.keystore(),
+					sync_oracle: sync_service.clone(),
+					justification_sync_link: sync_service.clone(),
+					block_proposal_slot_portion: SlotProportion::new(2f32 / 3f32),
+					max_block_proposal_slot_portion: None,
+					telemetry: telemetry.as_ref().map(|x| x.handle()),
+					compatibility_mode: Default::default(),
+				},
+			)?;
+
+			// the AURA authoring task is considered essential, i.e. if it
+			// fails we take down the service with it.
+			task_manager.spawn_essential_handle().spawn_blocking(
+				"aura",
+				Some("block-authoring"),
+				aura,
+			);
+		}
 	}
 
 	if enable_grandpa {
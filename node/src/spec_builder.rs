@@ -0,0 +1,347 @@
+//! The `generate-spec` subcommand: build chain specs from a declarative genesis description.
+//!
+//! The description is a TOML or YAML file (picked by its extension) listing the authorities, the
+//! endowed accounts, the sudo key and the chain properties. See `node/res/genesis.example.toml`
+//! for a complete example. The `staging` chain spec is built from a [`GenesisDescription`] too.
+//!
+//! Keys and accounts are given either as an SS58 address, as a `0x`-prefixed hex public key or as
+//! a secret URI starting with `/`, e.g. `//Alice`. Secret URIs are only meant for test networks.
+
+use crate::chain_spec::{self, ChainSpec};
+use node_template_runtime::{AccountId, Balance, WASM_BINARY};
+use sc_chain_spec::GenesisConfigBuilderRuntimeCaller;
+use sc_network::config::MultiaddrWithPeerId;
+use sc_service::{ChainType, Properties};
+use sc_telemetry::TelemetryEndpoints;
+use serde::{
+	de::{self, Deserializer, Unexpected},
+	Deserialize,
+};
+use serde_json::Value;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_core::{
+	crypto::{ByteArray, Pair, Public, Ss58Codec},
+	ed25519, sr25519,
+};
+use std::{fs, path::PathBuf};
+
+/// Generate a plain and a raw chain spec from a TOML or YAML genesis description.
+#[derive(Debug, clap::Parser)]
+pub struct GenerateSpecCmd {
+	/// The genesis description, a `.toml`, `.yaml` or `.yml` file.
+	pub input: PathBuf,
+
+	/// Where to write the human-readable chain spec.
+	#[arg(long, default_value = "chain_spec.json")]
+	pub plain: PathBuf,
+
+	/// Where to write the raw chain spec, as used to start a node.
+	#[arg(long, default_value = "chain_spec_raw.json")]
+	pub raw: PathBuf,
+}
+
+/// A chain type as written in a genesis description.
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpecChainType {
+	Development,
+	Local,
+	Live,
+}
+
+impl From<SpecChainType> for ChainType {
+	fn from(chain_type: SpecChainType) -> Self {
+		match chain_type {
+			SpecChainType::Development => ChainType::Development,
+			SpecChainType::Local => ChainType::Local,
+			SpecChainType::Live => ChainType::Live,
+		}
+	}
+}
+
+/// A validator of the genesis description.
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Authority {
+	/// Derive the account and the Aura key (sr25519) and the GRANDPA key (ed25519) from a
+	/// secret URI.
+	Seed { seed: String },
+	/// Give every key explicitly.
+	Keys { account: String, aura: String, grandpa: String },
+}
+
+/// An initial `pallet_template` value of the genesis description.
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateValue {
+	pub account: String,
+	pub value: u32,
+}
+
+/// An endowed account of the genesis description.
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Endowment {
+	pub account: String,
+	/// The balance in the smallest denomination, as an integer or, beyond the integers of TOML
+	/// and JSON, as a string of decimal digits.
+	#[serde(deserialize_with = "deserialize_balance")]
+	pub balance: Balance,
+}
+
+/// Deserialize a balance given as an integer or as a string of decimal digits, which may be
+/// separated by `_` as in TOML integers.
+///
+/// The balance goes through a [`Value`], which keeps the digits of JSON integers beyond `u64`
+/// when `serde_json` is built with `arbitrary_precision`.
+fn deserialize_balance<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Balance, D::Error> {
+	let value = match Value::deserialize(deserializer)? {
+		Value::Number(n) => n.to_string(),
+		Value::String(s) => s,
+		_ => return Err(de::Error::invalid_type(Unexpected::Other("non-numeric value"), &EXPECTED)),
+	};
+	let digits = value.replace('_', "");
+	if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+		return Err(de::Error::invalid_value(Unexpected::Str(&value), &EXPECTED))
+	}
+	digits
+		.parse()
+		.map_err(|_| de::Error::invalid_value(Unexpected::Str(&value), &"a balance within `u128`"))
+}
+
+/// What [`deserialize_balance`] expects.
+const EXPECTED: &str = "a non-negative integer, or a string of decimal digits";
+
+/// The genesis description read by [`GenerateSpecCmd`].
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisDescription {
+	pub name: String,
+	pub id: String,
+	pub chain_type: SpecChainType,
+	/// The `pallet_sudo` key.
+	pub sudo: String,
+	/// The validators. Their accounts must be endowed with at least the validator bond of the
+	/// runtime, which is held at genesis.
+	pub authorities: Vec<Authority>,
+	#[serde(default)]
+	pub endowed: Vec<Endowment>,
+	/// The initial `pallet_template::Something` values. Their accounts must be endowed.
+	#[serde(default)]
+	pub template_values: Vec<TemplateValue>,
+	/// The chain properties, e.g. `tokenSymbol` and `tokenDecimals`. They override the defaults
+	/// of [`chain_spec::chain_properties`].
+	#[serde(default)]
+	pub properties: Properties,
+	/// The nodes a new node connects to first.
+	#[serde(default)]
+	pub boot_nodes: Vec<MultiaddrWithPeerId>,
+	/// The telemetry servers, each with the verbosity level it is sent.
+	#[serde(default)]
+	pub telemetry_endpoints: Vec<(String, u8)>,
+}
+
+impl GenerateSpecCmd {
+	/// Run the command.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let spec = self.read_description()?.chain_spec(serde_json::json!({}))?;
+
+		fs::write(&self.plain, spec.as_json(false)?)?;
+		fs::write(&self.raw, spec.as_json(true)?)?;
+		Ok(())
+	}
+
+	fn read_description(&self) -> sc_cli::Result<GenesisDescription> {
+		let content = fs::read_to_string(&self.input)?;
+		let description = match self.input.extension().and_then(|e| e.to_str()) {
+			Some("toml") => toml::from_str(&content).map_err(|e| e.to_string()),
+			Some("yaml" | "yml") => serde_yaml::from_str(&content).map_err(|e| e.to_string()),
+			_ => Err("expected a `.toml`, `.yaml` or `.yml` file".to_string()),
+		};
+		description.map_err(|e| {
+			format!("Invalid genesis description {}: {}", self.input.display(), e).into()
+		})
+	}
+}
+
+/// Parse a public key given as an SS58 address or in hex, or derive it from a secret URI.
+fn parse_public<TPublic: Public + Ss58Codec>(s: &str) -> Result<TPublic, String> {
+	if s.starts_with('/') {
+		TPublic::Pair::from_string(s, None)
+			.map(|pair| pair.public())
+			.map_err(|e| format!("invalid secret URI `{}`: {:?}", s, e))
+	} else if s.starts_with("0x") {
+		sp_core::bytes::from_hex(s)
+			.ok()
+			.and_then(|bytes| TPublic::from_slice(&bytes).ok())
+			.ok_or_else(|| format!("invalid hex public key `{}`", s))
+	} else {
+		TPublic::from_ss58check(s).map_err(|e| format!("invalid SS58 address `{}`: {:?}", s, e))
+	}
+}
+
+fn parse_account(s: &str) -> Result<AccountId, String> {
+	parse_public::<sr25519::Public>(s).map(Into::into)
+}
+
+impl Authority {
+	fn keys(&self) -> Result<(AccountId, AuraId, GrandpaId), String> {
+		match self {
+			Authority::Seed { seed } => Ok((
+				parse_account(seed)?,
+				parse_public::<sr25519::Public>(seed)?.into(),
+				parse_public::<ed25519::Public>(seed)?.into(),
+			)),
+			Authority::Keys { account, aura, grandpa } => Ok((
+				parse_account(account)?,
+				parse_public::<sr25519::Public>(aura)?.into(),
+				parse_public::<ed25519::Public>(grandpa)?.into(),
+			)),
+		}
+	}
+}
+
+impl GenesisDescription {
+	/// The chain spec described, with the genesis config patch of the description applied on top
+	/// of `base`.
+	///
+	/// Fails if the runtime rejects the resulting genesis config.
+	pub fn chain_spec(&self, mut base: Value) -> Result<ChainSpec, String> {
+		let wasm = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+		merge(&mut base, self.genesis_patch()?);
+
+		GenesisConfigBuilderRuntimeCaller::<()>::new(wasm)
+			.get_storage_for_patch(base.clone())
+			.map_err(|e| format!("Invalid genesis config: {}", e))?;
+
+		let mut properties = chain_spec::chain_properties();
+		properties.extend(self.properties.clone());
+		let mut builder = ChainSpec::builder(wasm, None)
+			.with_name(&self.name)
+			.with_id(&self.id)
+			.with_chain_type(self.chain_type.into())
+			.with_properties(properties)
+			.with_boot_nodes(self.boot_nodes.clone())
+			.with_genesis_config_patch(base);
+		if !self.telemetry_endpoints.is_empty() {
+			let endpoints = TelemetryEndpoints::new(self.telemetry_endpoints.clone())
+				.map_err(|e| format!("Invalid telemetry endpoint: {}", e))?;
+			builder = builder.with_telemetry_endpoints(endpoints);
+		}
+		Ok(builder.build())
+	}
+
+	/// Every key and account of the description, as written and as the raw public key.
+	pub fn public_keys(&self) -> Result<Vec<(&str, Vec<u8>)>, String> {
+		let mut keys = Vec::new();
+		for authority in &self.authorities {
+			let (account, aura, grandpa) = authority.keys()?;
+			let (account_str, aura_str, grandpa_str) = match authority {
+				Authority::Seed { seed } => (seed, seed, seed),
+				Authority::Keys { account, aura, grandpa } => (account, aura, grandpa),
+			};
+			keys.push((account_str.as_str(), AsRef::<[u8]>::as_ref(&account).to_vec()));
+			keys.push((aura_str.as_str(), aura.to_raw_vec()));
+			keys.push((grandpa_str.as_str(), grandpa.to_raw_vec()));
+		}
+		let accounts = self
+			.endowed
+			.iter()
+			.map(|e| &e.account)
+			.chain(self.template_values.iter().map(|t| &t.account))
+			.chain(Some(&self.sudo));
+		for account in accounts {
+			keys.push((account.as_str(), AsRef::<[u8]>::as_ref(&parse_account(account)?).to_vec()));
+		}
+		Ok(keys)
+	}
+
+	/// The genesis config patch described.
+	fn genesis_patch(&self) -> Result<Value, String> {
+		let authorities =
+			self.authorities.iter().map(Authority::keys).collect::<Result<Vec<_>, _>>()?;
+		let endowed = self
+			.endowed
+			.iter()
+			.map(|e| Ok((parse_account(&e.account)?, e.balance)))
+			.collect::<Result<Vec<_>, String>>()?;
+		let template_values = self
+			.template_values
+			.iter()
+			.map(|t| Ok((parse_account(&t.account)?, t.value)))
+			.collect::<Result<Vec<_>, String>>()?;
+
+		Ok(serde_json::json!({
+			"balances": {
+				"balances": endowed,
+			},
+			"validatorSet": {
+				"initialValidators": authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>(),
+			},
+			"session": {
+				"keys": authorities
+					.iter()
+					.map(|x| {
+						(x.0.clone(), x.0.clone(), chain_spec::session_keys(x.1.clone(), x.2.clone()))
+					})
+					.collect::<Vec<_>>(),
+			},
+			"sudo": {
+				"key": Some(parse_account(&self.sudo)?),
+			},
+			"templateModule": {
+				"something": template_values,
+			},
+		}))
+	}
+}
+
+/// Apply `patch` to `base`, the way chain specs apply a genesis config patch.
+fn merge(base: &mut Value, patch: Value) {
+	match (base, patch) {
+		(Value::Object(base), Value::Object(patch)) =>
+			for (key, value) in patch {
+				merge(base.entry(key).or_insert(Value::Null), value);
+			},
+		(base, patch) => *base = patch,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn balance(toml: &str) -> Result<Balance, String> {
+		toml::from_str::<Endowment>(&format!("account = \"//Alice\"\nbalance = {}", toml))
+			.map(|e| e.balance)
+			.map_err(|e| e.to_string())
+	}
+
+	#[test]
+	fn balances_are_integers_or_decimal_strings() {
+		assert_eq!(balance("1_000_000_000_000_000_000"), Ok(1_000_000_000_000_000_000));
+		// Beyond `u64`, as endowments of more than about 18.4 million UNIT are.
+		assert_eq!(
+			balance("\"100_000_000_000_000_000_000_000\""),
+			Ok(100_000_000_000_000_000_000_000)
+		);
+		assert!(balance("-1").is_err());
+		assert!(balance("\"1e21\"").is_err());
+		assert!(balance("\"\"").is_err());
+	}
+
+	#[test]
+	fn large_balances_reach_the_genesis_patch() {
+		let description: GenesisDescription = serde_json::from_str(
+			r#"{
+				"name": "Test", "id": "test", "chain_type": "local", "sudo": "//Alice",
+				"authorities": [{ "seed": "//Alice" }],
+				"endowed": [{ "account": "//Alice", "balance": "100000000000000000000000" }]
+			}"#,
+		)
+		.unwrap();
+		let patch = description.genesis_patch().unwrap();
+		assert_eq!(patch["balances"]["balances"][0][1].to_string(), "100000000000000000000000");
+	}
+}
@@ -0,0 +1,85 @@
+//! RPC interface for the template pallet.
+//!
+//! Exposes the values stored in `pallet_template::Something` through the `template_getSomething`
+//! and `template_getSomethingAt` methods, backed by the [`TemplateRuntimeApi`].
+
+#![warn(missing_docs)]
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_template_runtime_api::TemplateApi as TemplateRuntimeApi;
+
+/// Template pallet RPC methods.
+#[rpc(client, server)]
+pub trait TemplateApi<BlockHash, AccountId> {
+	/// Get the value stored by `who` at the best block.
+	#[method(name = "template_getSomething")]
+	fn get_something(&self, who: AccountId) -> RpcResult<Option<u32>>;
+
+	/// Get the value stored by `who` at the given block.
+	#[method(name = "template_getSomethingAt")]
+	fn get_something_at(&self, who: AccountId, at: BlockHash) -> RpcResult<Option<u32>>;
+}
+
+/// Provides RPC methods to query the template pallet's state.
+pub struct Template<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Template<C, Block> {
+	/// Creates a new instance of the Template RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId> TemplateApiServer<<Block as BlockT>::Hash, AccountId>
+	for Template<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TemplateRuntimeApi<Block, AccountId>,
+	AccountId: Codec + Send + 'static,
+{
+	fn get_something(&self, who: AccountId) -> RpcResult<Option<u32>> {
+		self.get_something_at(who, self.client.info().best_hash)
+	}
+
+	fn get_something_at(&self, who: AccountId, at: Block::Hash) -> RpcResult<Option<u32>> {
+		self.client
+			.runtime_api()
+			.get_something(at, who)
+			.map_err(|e| -> ErrorObjectOwned {
+				ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to query the stored value.",
+					Some(e.to_string()),
+				)
+			})
+	}
+}
@@ -0,0 +1,18 @@
+//! Runtime API definition for the template pallet.
+//!
+//! This API is implemented by the runtime in `runtime/src/lib.rs` and queried by the
+//! `pallet-template-rpc` crate to serve the `template_*` RPC methods.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to read the state of the template pallet.
+	pub trait TemplateApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// Get the value stored by `who`, if any.
+		fn get_something(who: AccountId) -> Option<u32>;
+	}
+}
@@ -5,7 +5,20 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Template;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::traits::fungible::{Inspect, Mutate};
+use frame_system::{
+	offchain::{AppCrypto, SignedPayload},
+	RawOrigin,
+};
+use sp_runtime::RuntimeAppPublic;
+
+/// Creates a caller that can afford the storage deposit.
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	let balance = T::Currency::minimum_balance() + T::SomethingDeposit::get() * 10u32.into();
+	T::Currency::set_balance(&caller, balance);
+	caller
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -14,21 +27,69 @@ mod benchmarks {
 	#[benchmark]
 	fn do_something() {
 		let value = 100u32.into();
-		let caller: T::AccountId = whitelisted_caller();
+		let caller = funded_caller::<T>();
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		do_something(RawOrigin::Signed(caller.clone()), value);
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert_eq!(Something::<T>::get(&caller).map(|info| info.value), Some(value));
 	}
 
 	#[benchmark]
 	fn cause_error() {
-		Something::<T>::put(100u32);
 		let caller: T::AccountId = whitelisted_caller();
+		let block_number = frame_system::Pallet::<T>::block_number();
+		Something::<T>::insert(
+			&caller,
+			SomethingInfo { value: 100u32, who: caller.clone(), block_number },
+		);
+		#[extrinsic_call]
+		cause_error(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(Something::<T>::get(&caller).map(|info| info.value), Some(101u32));
+	}
+
+	#[benchmark]
+	fn clear_something() {
+		let caller = funded_caller::<T>();
+		Pallet::<T>::do_something(RawOrigin::Signed(caller.clone()).into(), 100u32)
+			.expect("caller can afford the deposit; qed");
+		#[extrinsic_call]
+		clear_something(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(Something::<T>::get(&caller), None);
+	}
+
+	#[benchmark]
+	fn remove_something() {
+		let owner = funded_caller::<T>();
+		Pallet::<T>::do_something(RawOrigin::Signed(owner.clone()).into(), 100u32)
+			.expect("owner can afford the deposit; qed");
+		#[extrinsic_call]
+		remove_something(RawOrigin::Root, owner.clone());
+
+		assert_eq!(Something::<T>::get(&owner), None);
+	}
+
+	#[benchmark]
+	fn submit_something_unsigned() {
+		// Sign the payload with a fresh key of the offchain worker's crypto.
+		let key =
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(
+				None,
+			);
+		let public: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic =
+			key.into();
+		let payload = SomethingPayload {
+			block_number: frame_system::Pallet::<T>::block_number(),
+			something: 100u32,
+			public: public.into(),
+		};
+		let signature = SignedPayload::<T>::sign::<T::AuthorityId>(&payload)
+			.expect("the key was just generated; qed");
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		submit_something_unsigned(RawOrigin::None, payload, signature);
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert_eq!(OffchainSomething::<T>::get().map(|info| info.value), Some(100u32));
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
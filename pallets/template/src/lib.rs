@@ -7,12 +7,21 @@
 //! ## Overview
 //!
 //! This template pallet contains basic examples of:
-//! - declaring a storage item that stores a single `u32` value
+//! - declaring a storage map that stores a `u32` value per account
+//! - versioning storage and migrating it between layouts (see [`migrations`])
+//! - holding a storage deposit under a named [`HoldReason`] through the
+//!   [`fungible`](frame_support::traits::fungible) traits
+//! - seeding storage at genesis through a [`GenesisConfig`]
 //! - declaring and using events
 //! - declaring and using errors
-//! - a dispatchable function that allows a user to set a new value to storage and emits an event
-//!   upon success
+//! - a dispatchable function that allows a user to set a new value in their own storage slot and
+//!   emits an event upon success
+//! - a dispatchable function that clears the caller's value and returns their deposit
+//! - a dispatchable function that accepts either a signed or a root origin
+//! - bounding inputs by constants of the [`Config`] that are exposed in the metadata
 //! - another dispatchable function that causes a custom error to be thrown
+//! - an offchain worker that submits a value read from the local offchain storage, both in signed
+//!   transactions and in unsigned transactions carrying a signed payload (see [`KEY_TYPE`])
 //!
 //! Each pallet section is annotated with an attribute using the `#[pallet::...]` procedural macro.
 //! This macro generates the necessary code for a pallet to be aggregated into a FRAME runtime.
@@ -27,6 +36,8 @@
 //!   (denoted by the `#[pallet::config]` attribute). See: [`Config`].
 //! - A **means to store pallet-specific data** (denoted by the `#[pallet::storage]` attribute).
 //!   See: [`storage_types`].
+//! - A **genesis configuration** that initializes the storage of a new chain (denoted by the
+//!   `#[pallet::genesis_config]` attribute). See: [`GenesisConfig`].
 //! - A **declaration of the events** this pallet emits (denoted by the `#[pallet::event]`
 //!   attribute). See: [`Event`].
 //! - A **declaration of the errors** that this pallet can throw (denoted by the `#[pallet::error]`
@@ -39,6 +50,8 @@
 // We make sure this pallet uses `no_std` for compiling to Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -60,17 +73,94 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+// Storage migrations between the versions of this pallet's storage layout. They are applied by
+// listing them in the runtime's `Migrations` tuple.
+pub mod migrations;
+
+use sp_core::crypto::KeyTypeId;
+
+/// The key type of the keys the offchain worker signs with.
+///
+/// A node only submits values if its keystore holds a key of this type, e.g. inserted through the
+/// `author_insertKey` RPC.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"tmpl");
+
+/// The key in the persistent local offchain storage the offchain worker reads its input from.
+///
+/// The input is a SCALE-encoded `u32`, set e.g. through the `offchain_localStorageSet` RPC.
+pub const OFFCHAIN_INPUT_KEY: &[u8] = b"pallet-template::input";
+
+/// The key under which the offchain worker remembers the last value it submitted signed.
+const OFFCHAIN_SUBMITTED_KEY: &[u8] = b"pallet-template::submitted";
+
+const LOG_TARGET: &str = "runtime::template";
+
+/// The application crypto of [`KEY_TYPE`] keys.
+///
+/// The runtime uses [`crypto::AuthorityId`] as [`Config::AuthorityId`], so that the offchain
+/// worker signs with the sr25519 keys of the keystore.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::{Public as Sr25519Public, Signature as Sr25519Signature};
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Signs with the [`KEY_TYPE`] keys of the keystore.
+	pub struct AuthorityId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthorityId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = Sr25519Signature;
+		type GenericPublic = Sr25519Public;
+	}
+}
+
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
 #[frame_support::pallet]
 pub mod pallet {
 	// Import various useful types required by all FRAME pallets.
 	use super::*;
-	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
+	use alloc::vec::Vec;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{self, InspectHold, MutateHold},
+			tokens::Precision,
+			Contains,
+		},
+	};
+	use frame_system::{
+		offchain::{
+			AppCrypto, CreateSignedTransaction, SendSignedTransaction, SendUnsignedTransaction,
+			SignedPayload, Signer, SigningTypes,
+		},
+		pallet_prelude::*,
+	};
+	use sp_runtime::{
+		offchain::storage::StorageValueRef,
+		traits::{IdentifyAccount, Zero},
+	};
+
+	/// The balance type of the [`Config::Currency`] used for storage deposits.
+	pub type BalanceOf<T> = <<T as Config>::Currency as fungible::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	/// The [`SomethingInfo`] of a runtime.
+	pub type SomethingInfoOf<T> =
+		SomethingInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	// The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
 	// (`Call`s) in this pallet.
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// The pallet's configuration trait.
@@ -79,19 +169,129 @@ pub mod pallet {
 	/// These types are defined generically and made concrete when the pallet is declared in the
 	/// `runtime/src/lib.rs` file of your chain.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
 		/// The overarching runtime event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// A type representing the weights required by the dispatchables of this pallet.
 		type WeightInfo: WeightInfo;
+		/// The overarching hold reason, which [`HoldReason`] is part of.
+		type RuntimeHoldReason: From<HoldReason>;
+		/// The currency in which storage deposits are held.
+		type Currency: fungible::Mutate<Self::AccountId>
+			+ fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+		/// The amount held from an account for as long as it has a value stored.
+		#[pallet::constant]
+		type SomethingDeposit: Get<BalanceOf<Self>>;
+		/// The smallest value that can be stored.
+		#[pallet::constant]
+		type MinValue: Get<u32>;
+		/// The largest value that can be stored, including through [`Pallet::cause_error`].
+		#[pallet::constant]
+		type MaxValue: Get<u32>;
+		/// The crypto the offchain worker signs its transactions and payloads with.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+		/// The accounts whose keys may sign the payloads of unsigned transactions.
+		///
+		/// Unsigned transactions pay no fees, so anyone else must not be able to submit them.
+		type OffchainAuthorities: Contains<Self::AccountId>;
+		/// The priority of the unsigned transactions submitted by the offchain worker.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+	}
+
+	/// The reasons for which this pallet places holds on funds.
+	///
+	/// The runtime aggregates the hold reasons of all its pallets into a single
+	/// `RuntimeHoldReason` enum, which is what [`Config::Currency`] tracks holds by.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as the deposit for a value in [`Something`].
+		#[codec(index = 0)]
+		SomethingDeposit,
+	}
+
+	/// A value, together with the account that stored it and the block in which it was last
+	/// written.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct SomethingInfo<AccountId, BlockNumber> {
+		/// The stored value.
+		pub value: u32,
+		/// The account that stored `value`.
+		///
+		/// In [`Something`] this is the key the value is stored under, while for
+		/// [`OffchainSomething`] it is the account of the key that signed the payload.
+		pub who: AccountId,
+		/// The block in which `value` was last written.
+		pub block_number: BlockNumber,
 	}
 
 	/// A storage item for this pallet.
 	///
-	/// In this template, we are declaring a storage item called `Something` that stores a single
-	/// `u32` value. Learn more about runtime storage here: <https://docs.substrate.io/build/runtime-storage/>
+	/// In this template, we are declaring a storage map called `Something` that stores a
+	/// [`SomethingInfo`] for each account, so that callers can only ever overwrite their own value.
+	/// Learn more about runtime storage here: <https://docs.substrate.io/build/runtime-storage/>
 	#[pallet::storage]
-	pub type Something<T> = StorageValue<_, u32>;
+	pub type Something<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SomethingInfoOf<T>>;
+
+	/// The last value submitted in an unsigned transaction by an offchain worker, together with the
+	/// block of the offchain worker run that submitted it.
+	#[pallet::storage]
+	pub type OffchainSomething<T: Config> = StorageValue<_, SomethingInfoOf<T>>;
+
+	/// A value computed by an offchain worker, signed with the key it is submitted for.
+	///
+	/// The signature of the payload, rather than the transaction, authenticates
+	/// [`Pallet::submit_something_unsigned`].
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct SomethingPayload<Public, BlockNumber> {
+		/// The block of the offchain worker run that computed the value.
+		pub block_number: BlockNumber,
+		/// The computed value.
+		pub something: u32,
+		/// The key that signed the payload.
+		pub public: Public,
+	}
+
+	impl<T: SigningTypes> SignedPayload<T> for SomethingPayload<T::Public, BlockNumberFor<T>> {
+		fn public(&self) -> T::Public {
+			self.public.clone()
+		}
+	}
+
+	/// The initial state of this pallet in the genesis block.
+	///
+	/// Each value in `something` is stored for its account as if the account had called
+	/// [`Pallet::do_something`], so the account must be endowed with enough funds to cover
+	/// [`Config::SomethingDeposit`].
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The initial values, by the account they belong to.
+		pub something: Vec<(T::AccountId, u32)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let block_number = frame_system::Pallet::<T>::block_number();
+			for (who, value) in &self.something {
+				assert!(!Something::<T>::contains_key(who), "duplicate account in genesis values");
+				assert!(
+					(T::MinValue::get()..=T::MaxValue::get()).contains(value),
+					"genesis values must be within `MinValue` and `MaxValue`"
+				);
+				T::Currency::hold(
+					&HoldReason::SomethingDeposit.into(),
+					who,
+					T::SomethingDeposit::get(),
+				)
+				.expect("genesis accounts must afford the deposit of their value");
+				let info = SomethingInfo { value: *value, who: who.clone(), block_number };
+				Something::<T>::insert(who, info);
+			}
+		}
+	}
 
 	/// Events that functions in this pallet can emit.
 	///
@@ -113,6 +313,18 @@ pub mod pallet {
 			/// The account who set the new value.
 			who: T::AccountId,
 		},
+		/// A user has cleared their value and got their deposit back.
+		SomethingCleared {
+			/// The account whose value was removed.
+			who: T::AccountId,
+		},
+		/// An offchain worker has submitted a new value in an unsigned transaction.
+		OffchainSomethingStored {
+			/// The new value set.
+			something: u32,
+			/// The account of the key that signed the value.
+			who: T::AccountId,
+		},
 	}
 
 	/// Errors that can be returned by this pallet.
@@ -129,6 +341,14 @@ pub mod pallet {
 		NoneValue,
 		/// There was an attempt to increment the value in storage over `u32::MAX`.
 		StorageOverflow,
+		/// The value is larger than [`Config::MaxValue`].
+		ValueTooLarge,
+		/// The value is smaller than [`Config::MinValue`].
+		ValueTooSmall,
+		/// The caller tried to remove a value stored by another account.
+		NotOwner,
+		/// The value is already stored, so storing it again would change nothing.
+		Unchanged,
 	}
 
 	/// The pallet's dispatchable functions ([`Call`]s).
@@ -146,18 +366,45 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// An example dispatchable that takes a single u32 value as a parameter, writes the value
-		/// to storage and emits an event.
+		/// to the caller's storage slot and emits an event.
 		///
 		/// It checks that the _origin_ for this call is _Signed_ and returns a dispatch
 		/// error if it isn't. Learn more about origins here: <https://docs.substrate.io/build/origins/>
+		///
+		/// The first time a caller stores a value, [`Config::SomethingDeposit`] is put on hold in
+		/// their account under [`HoldReason::SomethingDeposit`]. It is released by
+		/// [`Pallet::clear_something`].
+		///
+		/// ## Errors
+		///
+		/// - If `something` is out of the configured bounds ([`Error::ValueTooLarge`],
+		///   [`Error::ValueTooSmall`])
+		/// - If the caller already stores `something` ([`Error::Unchanged`])
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::do_something())]
 		pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
 			// Check that the extrinsic was signed and get the signer.
 			let who = ensure_signed(origin)?;
 
+			// Validate the new value.
+			ensure!(something <= T::MaxValue::get(), Error::<T>::ValueTooLarge);
+			ensure!(something >= T::MinValue::get(), Error::<T>::ValueTooSmall);
+			let old = Something::<T>::get(&who);
+			ensure!(old.as_ref().map(|info| info.value) != Some(something), Error::<T>::Unchanged);
+
+			// Take a deposit for the new storage item, unless the caller already paid for one.
+			if old.is_none() {
+				T::Currency::hold(
+					&HoldReason::SomethingDeposit.into(),
+					&who,
+					T::SomethingDeposit::get(),
+				)?;
+			}
+
 			// Update storage.
-			Something::<T>::put(something);
+			let block_number = frame_system::Pallet::<T>::block_number();
+			let info = SomethingInfo { value: something, who: who.clone(), block_number };
+			Something::<T>::insert(&who, info);
 
 			// Emit an event.
 			Self::deposit_event(Event::SomethingStored { something, who });
@@ -168,8 +415,8 @@ pub mod pallet {
 
 		/// An example dispatchable that may throw a custom error.
 		///
-		/// It checks that the caller is a signed origin and reads the current value from the
-		/// `Something` storage item. If a current value exists, it is incremented by 1 and then
+		/// It checks that the caller is a signed origin and reads the caller's current value from
+		/// the `Something` storage item. If a current value exists, it is incremented by 1 and then
 		/// written back to storage.
 		///
 		/// ## Errors
@@ -179,24 +426,208 @@ pub mod pallet {
 		/// - If no value has been set ([`Error::NoneValue`])
 		/// - If incrementing the value in storage causes an arithmetic overflow
 		///   ([`Error::StorageOverflow`])
+		/// - If the incremented value exceeds [`Config::MaxValue`] ([`Error::ValueTooLarge`])
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::cause_error())]
 		pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
+			let who = ensure_signed(origin)?;
 
 			// Read a value from storage.
-			match Something::<T>::get() {
+			match Something::<T>::get(&who) {
 				// Return an error if the value has not been set.
 				None => Err(Error::<T>::NoneValue.into()),
 				Some(old) => {
 					// Increment the value read from storage. This will cause an error in the event
 					// of overflow.
-					let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+					let value = old.value.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+					ensure!(value <= T::MaxValue::get(), Error::<T>::ValueTooLarge);
 					// Update the value in storage with the incremented result.
-					Something::<T>::put(new);
+					let block_number = frame_system::Pallet::<T>::block_number();
+					let info = SomethingInfo { value, who: who.clone(), block_number };
+					Something::<T>::insert(&who, info);
 					Ok(())
 				},
 			}
 		}
+
+		/// Remove the caller's value from storage and release their storage deposit.
+		///
+		/// ## Errors
+		///
+		/// - If the caller has no value stored ([`Error::NoneValue`])
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::clear_something())]
+		pub fn clear_something(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_clear_something(who)
+		}
+
+		/// Remove the value of `owner` from storage and release their storage deposit.
+		///
+		/// Root may remove any value, e.g. to clean up spam, while signed callers may only remove
+		/// their own.
+		///
+		/// ## Errors
+		///
+		/// - If a signed caller is not `owner` ([`Error::NotOwner`])
+		/// - If `owner` has no value stored ([`Error::NoneValue`])
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::remove_something())]
+		pub fn remove_something(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+			if let Some(who) = ensure_signed_or_root(origin)? {
+				ensure!(who == owner, Error::<T>::NotOwner);
+			}
+			Self::do_clear_something(owner)
+		}
+
+		/// Store a value computed by an offchain worker in [`OffchainSomething`].
+		///
+		/// This is an unsigned transaction: instead of paying fees, it is authenticated by the
+		/// signature of `payload`, which [`Pallet::validate_unsigned`] checks together with the
+		/// bounds of the value before the transaction enters the pool or a block. The key that
+		/// signed `payload` must belong to one of the [`Config::OffchainAuthorities`].
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::submit_something_unsigned())]
+		pub fn submit_something_unsigned(
+			origin: OriginFor<T>,
+			payload: SomethingPayload<T::Public, BlockNumberFor<T>>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let SomethingPayload { block_number, something, public } = payload;
+			let who = public.into_account();
+			OffchainSomething::<T>::put(SomethingInfo {
+				value: something,
+				who: who.clone(),
+				block_number,
+			});
+
+			Self::deposit_event(Event::OffchainSomethingStored { something, who });
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Submit the value of the local offchain storage at [`crate::OFFCHAIN_INPUT_KEY`],
+		/// clamped to the configured bounds, for the [`crate::KEY_TYPE`] key of the node.
+		///
+		/// The value is submitted twice: as the node's own value through a signed
+		/// [`Call::do_something`], and through the unsigned
+		/// [`Call::submit_something_unsigned`]. Each is skipped if the value was submitted
+		/// already.
+		fn offchain_worker(block_number: BlockNumberFor<T>) {
+			let Some(value) = Self::offchain_input() else { return };
+
+			if let Err(e) = Self::submit_signed(value) {
+				log::warn!(target: LOG_TARGET, "Failed to submit a signed value: {}", e);
+			}
+			if let Err(e) = Self::submit_unsigned(block_number, value) {
+				log::warn!(target: LOG_TARGET, "Failed to submit an unsigned value: {}", e);
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::submit_something_unsigned { payload, signature } = call else {
+				return InvalidTransaction::Call.into()
+			};
+
+			if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+				return InvalidTransaction::BadProof.into()
+			}
+			if !T::OffchainAuthorities::contains(&payload.public.clone().into_account()) {
+				return InvalidTransaction::BadProof.into()
+			}
+			if !(T::MinValue::get()..=T::MaxValue::get()).contains(&payload.something) {
+				return InvalidTransaction::Call.into()
+			}
+			if payload.block_number > frame_system::Pallet::<T>::block_number() {
+				return InvalidTransaction::Future.into()
+			}
+			// Reject replays of payloads older than the stored value.
+			if OffchainSomething::<T>::get()
+				.is_some_and(|info| payload.block_number <= info.block_number)
+			{
+				return InvalidTransaction::Stale.into()
+			}
+
+			ValidTransaction::with_tag_prefix("TemplateOffchainWorker")
+				.priority(T::UnsignedPriority::get())
+				// At most one value per offchain worker run is accepted.
+				.and_provides(payload.block_number)
+				.longevity(5)
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The input of the offchain worker, clamped to the configured bounds, or `None` if there
+		/// is none.
+		fn offchain_input() -> Option<u32> {
+			let input =
+				StorageValueRef::persistent(OFFCHAIN_INPUT_KEY).get::<u32>().ok().flatten()?;
+			Some(input.max(T::MinValue::get()).min(T::MaxValue::get()))
+		}
+
+		/// Store `value` as the node's own value, unless it submitted it already.
+		///
+		/// A transaction that is dropped from the pool is not retried until the input changes.
+		fn submit_signed(value: u32) -> Result<(), &'static str> {
+			let submitted = StorageValueRef::persistent(OFFCHAIN_SUBMITTED_KEY);
+			if submitted.get::<u32>().ok().flatten() == Some(value) {
+				return Ok(())
+			}
+
+			let (_, result) = Signer::<T, T::AuthorityId>::any_account()
+				.send_signed_transaction(|_| Call::do_something { something: value })
+				.ok_or("no local key to sign with")?;
+			result.map_err(|()| "transaction rejected by the pool")?;
+			submitted.set(&value);
+			Ok(())
+		}
+
+		/// Submit `value` as [`OffchainSomething`], unless it is stored already.
+		fn submit_unsigned(
+			block_number: BlockNumberFor<T>,
+			value: u32,
+		) -> Result<(), &'static str> {
+			if OffchainSomething::<T>::get().map(|info| info.value) == Some(value) {
+				return Ok(())
+			}
+
+			let (_, result) = Signer::<T, T::AuthorityId>::any_account()
+				.send_unsigned_transaction(
+					|account| SomethingPayload {
+						block_number,
+						something: value,
+						public: account.public.clone(),
+					},
+					|payload, signature| Call::submit_something_unsigned { payload, signature },
+				)
+				.ok_or("no local key to sign with")?;
+			result.map_err(|()| "transaction rejected by the pool")
+		}
+
+		/// Remove the value of `who` and release its deposit.
+		fn do_clear_something(who: T::AccountId) -> DispatchResult {
+			Something::<T>::take(&who).ok_or(Error::<T>::NoneValue)?;
+			// Release whatever was held, in case `SomethingDeposit` changed in the meantime. The
+			// value migrated from version 0 has no deposit, and its owner may have no balance.
+			let reason = HoldReason::SomethingDeposit.into();
+			let deposit = T::Currency::balance_on_hold(&reason, &who);
+			if !deposit.is_zero() {
+				T::Currency::release(&reason, &who, deposit, Precision::Exact)?;
+			}
+
+			Self::deposit_event(Event::SomethingCleared { who });
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,9 @@
+//! Storage migrations for the template pallet.
+
+/// Moves the global `Something: u32` into the per-account
+/// [`SomethingInfo`](crate::SomethingInfo)s that replace it.
+pub mod v1;
+
+/// Moves the storage deposits from reserves to holds under
+/// [`HoldReason::SomethingDeposit`](crate::HoldReason::SomethingDeposit).
+pub mod v2;
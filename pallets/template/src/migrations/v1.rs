@@ -0,0 +1,112 @@
+use crate::{Config, Pallet, Something, SomethingInfo, LOG_TARGET};
+use core::marker::PhantomData;
+use frame_support::{
+	migrations::VersionedMigration,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+
+#[cfg(feature = "try-runtime")]
+extern crate alloc;
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use frame_support::{ensure, sp_runtime::TryRuntimeError};
+
+/// The storage layout of version 0, kept around to seed and read the old value.
+pub mod v0 {
+	use super::Config;
+	use frame_support::storage_alias;
+
+	/// The single value any signed origin could overwrite, before values were stored per account.
+	#[storage_alias]
+	pub type Something<T: Config> = StorageValue<crate::Pallet<T>, u32>;
+}
+
+/// Moves the global `u32` of version 0 into [`Something`], as the value of `LegacyOwner`.
+///
+/// Version 0 kept a single value, written by whichever account called last, so there is no owner
+/// to attribute it to: the runtime picks the account to keep it under instead. The value is
+/// recorded as written in the block of the upgrade and no deposit is held for it, as nobody paid
+/// one; clearing it releases nothing. Should `LegacyOwner` already have a value of its own, that
+/// one is kept and the old value is dropped.
+///
+/// The old value lives under the bare prefix of the new map, which iterating over the map never
+/// visits, so it is removed explicitly.
+///
+/// This does not check or bump the storage version; use [`MigrateV0ToV1`] instead, which only
+/// runs it while the on-chain version is 0.
+pub struct InnerMigrateV0ToV1<T, LegacyOwner>(PhantomData<(T, LegacyOwner)>);
+
+impl<T, LegacyOwner> InnerMigrateV0ToV1<T, LegacyOwner>
+where
+	T: Config,
+	LegacyOwner: Get<T::AccountId>,
+{
+	/// The value of version 0 the migration carries over, if any.
+	#[cfg(feature = "try-runtime")]
+	fn carried_value() -> Option<u32> {
+		v0::Something::<T>::get().filter(|_| !Something::<T>::contains_key(LegacyOwner::get()))
+	}
+}
+
+impl<T, LegacyOwner> OnRuntimeUpgrade for InnerMigrateV0ToV1<T, LegacyOwner>
+where
+	T: Config,
+	LegacyOwner: Get<T::AccountId>,
+{
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		Ok((Something::<T>::iter_keys().count() as u32, Self::carried_value()).encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let Some(value) = v0::Something::<T>::take() else { return T::DbWeight::get().reads(1) };
+
+		let who = LegacyOwner::get();
+		if Something::<T>::contains_key(&who) {
+			log::warn!(
+				target: LOG_TARGET,
+				"Dropped the global value {} of version 0, as its owner already has a value",
+				value
+			);
+		} else {
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Something::<T>::insert(&who, SomethingInfo { value, who: who.clone(), block_number });
+			log::info!(target: LOG_TARGET, "Moved the global value {} of version 0", value);
+		}
+
+		// The old value and the entry of the owner, plus the block number.
+		T::DbWeight::get().reads_writes(3, 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let (count, carried) = <(u32, Option<u32>)>::decode(&mut &state[..])
+			.map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+		ensure!(!v0::Something::<T>::exists(), "The global value of version 0 was not removed");
+		ensure!(
+			Something::<T>::iter_keys().count() as u32 == count + carried.is_some() as u32,
+			"The number of stored values does not account for the global value"
+		);
+		if let Some(value) = carried {
+			ensure!(
+				Something::<T>::get(LegacyOwner::get()).map(|info| info.value) == Some(value),
+				"The global value of version 0 was not moved to its owner"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Migrates the pallet storage from version 0 to 1, see [`InnerMigrateV0ToV1`].
+pub type MigrateV0ToV1<T, LegacyOwner> = VersionedMigration<
+	0,
+	1,
+	InnerMigrateV0ToV1<T, LegacyOwner>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
@@ -0,0 +1,95 @@
+use crate::{BalanceOf, Config, HoldReason, Pallet, Something};
+use core::marker::PhantomData;
+use frame_support::{
+	defensive,
+	migrations::VersionedMigration,
+	sp_runtime::traits::Zero,
+	traits::{fungible::MutateHold, Get, OnRuntimeUpgrade, ReservableCurrency},
+	weights::Weight,
+};
+
+#[cfg(feature = "try-runtime")]
+extern crate alloc;
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use frame_support::{ensure, sp_runtime::TryRuntimeError, traits::fungible::InspectHold};
+
+/// Unreserves the deposit of every stored value from `OldCurrency` and puts it on hold under
+/// [`HoldReason::SomethingDeposit`] instead.
+///
+/// Version 1 reserved [`Config::SomethingDeposit`] through a `ReservableCurrency`; `OldCurrency`
+/// must be that currency, which is usually the same balances pallet as [`Config::Currency`].
+///
+/// This does not check or bump the storage version; use [`MigrateV1ToV2`] instead, which only
+/// runs it while the on-chain version is 1.
+pub struct InnerMigrateV1ToV2<T, OldCurrency>(PhantomData<(T, OldCurrency)>);
+
+impl<T, OldCurrency> OnRuntimeUpgrade for InnerMigrateV1ToV2<T, OldCurrency>
+where
+	T: Config,
+	OldCurrency: ReservableCurrency<T::AccountId, Balance = BalanceOf<T>>,
+{
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		// The deposit each owner should end up holding, which is nothing for the value migrated
+		// from version 0.
+		let deposit = T::SomethingDeposit::get();
+		let deposits: Vec<(T::AccountId, BalanceOf<T>)> = Something::<T>::iter_keys()
+			.map(|who| {
+				let reserved = OldCurrency::reserved_balance(&who).min(deposit);
+				(who, reserved)
+			})
+			.collect();
+		Ok(deposits.encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let deposit = T::SomethingDeposit::get();
+		let mut migrated = 0u64;
+
+		for who in Something::<T>::iter_keys() {
+			migrated += 1;
+			// Only move what was actually reserved.
+			let missing = OldCurrency::unreserve(&who, deposit);
+			let amount = deposit - missing;
+			// Nothing was reserved for the value migrated from version 0, whose owner may not
+			// even have an account to hold from.
+			if amount.is_zero() {
+				continue
+			}
+			if T::Currency::hold(&HoldReason::SomethingDeposit.into(), &who, amount).is_err() {
+				defensive!("funds just unreserved can be held; qed");
+			}
+		}
+
+		// The key read, plus the account and holds read and written, per entry.
+		T::DbWeight::get().reads_writes(migrated.saturating_mul(3), migrated.saturating_mul(2))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let deposits = Vec::<(T::AccountId, BalanceOf<T>)>::decode(&mut &state[..])
+			.map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+		let reason = HoldReason::SomethingDeposit.into();
+		for (who, deposit) in deposits {
+			ensure!(
+				T::Currency::balance_on_hold(&reason, &who) == deposit,
+				"A storage deposit was not moved to a hold"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Migrates the pallet storage from version 1 to 2, see [`InnerMigrateV1ToV2`].
+pub type MigrateV1ToV2<T, OldCurrency> = VersionedMigration<
+	1,
+	2,
+	InnerMigrateV1ToV2<T, OldCurrency>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
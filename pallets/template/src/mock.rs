@@ -1,11 +1,22 @@
 use crate as pallet_template;
+use codec::Decode;
 use frame_support::{
 	derive_impl,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU16, ConstU32, ConstU64, Equals},
+};
+use frame_system::offchain::{
+	AppCrypto, CreateSignedTransaction, SendTransactionTypes, SigningTypes,
+};
+use sp_core::{
+	offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	H256,
 };
-use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{TestSignature, TestXt, UintAuthorityId},
+	traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentityLookup},
 	BuildStorage,
 };
 
@@ -16,6 +27,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		TemplateModule: pallet_template,
 	}
 );
@@ -38,7 +50,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -47,12 +59,121 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+}
+
+/// The deposit reserved for each stored value in the mock runtime.
+pub const SOMETHING_DEPOSIT: u64 = 10;
+/// The bounds of the values stored in the mock runtime.
+pub const MIN_VALUE: u32 = 1;
+pub const MAX_VALUE: u32 = 1_000;
+
+/// The extrinsics the offchain worker submits in the mock runtime.
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> SendTransactionTypes<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: UintAuthorityId,
+		account: u64,
+		_nonce: u64,
+	) -> Option<(RuntimeCall, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (account, ())))
+	}
+}
+
+/// Signs with the [`UintAuthorityId`] keys set through [`UintAuthorityId::set_all_keys`].
+pub struct TestAuthId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
 impl pallet_template::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type SomethingDeposit = ConstU64<SOMETHING_DEPOSIT>;
+	type MinValue = ConstU32<MIN_VALUE>;
+	type MaxValue = ConstU32<MAX_VALUE>;
+	type AuthorityId = TestAuthId;
+	type OffchainAuthorities = Equals<ConstU64<OFFCHAIN_ACCOUNT>>;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
 }
 
+/// The value account 4 stores at genesis.
+pub const GENESIS_VALUE: u32 = 21;
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 100), (2, 100), (3, 5), (4, 100)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	// Account 4 starts with a value, so that the genesis config is exercised too.
+	pallet_template::GenesisConfig::<Test> { something: vec![(4, GENESIS_VALUE)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	t.into()
+}
+
+/// The account the global value of storage version 0 is migrated to.
+pub const LEGACY_OWNER: u64 = 5;
+
+/// The account of the key the offchain worker signs with.
+pub const OFFCHAIN_ACCOUNT: u64 = 1;
+
+/// The externalities of [`new_test_ext`], with offchain worker extensions and a key of
+/// [`OFFCHAIN_ACCOUNT`], together with a function that takes the submitted transactions out of the
+/// pool.
+pub fn new_offchain_test_ext() -> (sp_io::TestExternalities, impl Fn() -> Vec<Extrinsic>) {
+	let mut t = new_test_ext();
+	let (offchain, _) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	t.register_extension(OffchainDbExt::new(offchain.clone()));
+	t.register_extension(OffchainWorkerExt::new(offchain));
+	t.register_extension(TransactionPoolExt::new(pool));
+	UintAuthorityId::set_all_keys(vec![OFFCHAIN_ACCOUNT]);
+
+	let take_transactions = move || {
+		pool_state
+			.write()
+			.transactions
+			.drain(..)
+			.map(|tx| Extrinsic::decode(&mut &*tx).unwrap())
+			.collect::<Vec<_>>()
+	};
+	(t, take_transactions)
 }
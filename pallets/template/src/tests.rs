@@ -1,5 +1,37 @@
-use crate::{mock::*, Error, Event, Something};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migrations::{
+		v1::{v0, MigrateV0ToV1},
+		v2::MigrateV1ToV2,
+	},
+	mock::*,
+	Call, Error, Event, HoldReason, OffchainSomething, Something, SomethingInfo, SomethingPayload,
+	OFFCHAIN_INPUT_KEY,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::{unhashed, StoragePrefixedMap},
+	traits::{
+		fungible::InspectHold, ConstU64, GetStorageVersion, Hooks, OnRuntimeUpgrade,
+		ReservableCurrency, StorageVersion,
+	},
+	unsigned::ValidateUnsigned,
+};
+use sp_core::offchain::StorageKind;
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::Dispatchable,
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	TokenError,
+};
+
+/// The storage deposit `who` currently has on hold.
+fn held_deposit(who: u64) -> u64 {
+	Balances::balance_on_hold(
+		&RuntimeHoldReason::TemplateModule(HoldReason::SomethingDeposit),
+		&who,
+	)
+}
 
 #[test]
 fn it_works_for_default_value() {
@@ -9,12 +41,30 @@ fn it_works_for_default_value() {
 		// Dispatch a signed extrinsic.
 		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
 		// Read pallet storage and assert an expected result.
-		assert_eq!(Something::<Test>::get(), Some(42));
+		assert_eq!(
+			Something::<Test>::get(1),
+			Some(SomethingInfo { value: 42, who: 1, block_number: 1 })
+		);
 		// Assert that the correct event was deposited
 		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
 	});
 }
 
+#[test]
+fn genesis_values_are_stored_with_their_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			Something::<Test>::get(4),
+			Some(SomethingInfo { value: GENESIS_VALUE, who: 4, block_number: 0 })
+		);
+		assert_eq!(held_deposit(4), SOMETHING_DEPOSIT);
+
+		// A seeded value can be used right away.
+		assert_ok!(TemplateModule::cause_error(RuntimeOrigin::signed(4)));
+		assert_eq!(Something::<Test>::get(4).map(|info| info.value), Some(GENESIS_VALUE + 1));
+	});
+}
+
 #[test]
 fn correct_error_for_none_value() {
 	new_test_ext().execute_with(|| {
@@ -25,3 +75,402 @@ fn correct_error_for_none_value() {
 		);
 	});
 }
+
+#[test]
+fn values_are_stored_per_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(2), 7));
+		assert_ok!(TemplateModule::cause_error(RuntimeOrigin::signed(2)));
+		// Each caller only touches their own slot.
+		assert_eq!(Something::<Test>::get(1).map(|info| info.value), Some(42));
+		assert_eq!(Something::<Test>::get(2).map(|info| info.value), Some(8));
+		assert_eq!(Something::<Test>::get(3), None);
+	});
+}
+
+#[test]
+fn deposit_is_held_once_and_released_on_clear() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
+		assert_eq!(held_deposit(1), SOMETHING_DEPOSIT);
+		// Overwriting an existing value does not take another deposit.
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 43));
+		assert_eq!(held_deposit(1), SOMETHING_DEPOSIT);
+
+		assert_ok!(TemplateModule::clear_something(RuntimeOrigin::signed(1)));
+		assert_eq!(Something::<Test>::get(1), None);
+		assert_eq!(held_deposit(1), 0);
+		System::assert_last_event(Event::SomethingCleared { who: 1 }.into());
+	});
+}
+
+#[test]
+fn do_something_rejects_values_out_of_bounds() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(1), MAX_VALUE + 1),
+			Error::<Test>::ValueTooLarge
+		);
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(1), MIN_VALUE - 1),
+			Error::<Test>::ValueTooSmall
+		);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), MAX_VALUE));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(2), MIN_VALUE));
+	});
+}
+
+#[test]
+fn cause_error_cannot_exceed_max_value() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), MAX_VALUE));
+		assert_noop!(
+			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
+			Error::<Test>::ValueTooLarge
+		);
+	});
+}
+
+#[test]
+fn storing_the_same_value_again_fails() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(1), 42),
+			Error::<Test>::Unchanged
+		);
+	});
+}
+
+#[test]
+fn only_the_owner_or_root_can_remove_a_value() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(2), 7));
+
+		assert_noop!(
+			TemplateModule::remove_something(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(TemplateModule::remove_something(RuntimeOrigin::signed(2), 2));
+		assert_eq!(Something::<Test>::get(2), None);
+		assert_eq!(held_deposit(2), 0);
+
+		assert_ok!(TemplateModule::remove_something(RuntimeOrigin::root(), 1));
+		assert_eq!(Something::<Test>::get(1), None);
+		assert_eq!(held_deposit(1), 0);
+		System::assert_last_event(Event::SomethingCleared { who: 1 }.into());
+
+		assert_noop!(
+			TemplateModule::remove_something(RuntimeOrigin::root(), 1),
+			Error::<Test>::NoneValue
+		);
+	});
+}
+
+#[test]
+fn bounds_are_exposed_in_metadata() {
+	let constants: Vec<_> = TemplateModule::pallet_constants_metadata()
+		.into_iter()
+		.map(|c| c.name)
+		.collect();
+	assert!(constants.contains(&"MinValue"));
+	assert!(constants.contains(&"MaxValue"));
+}
+
+#[test]
+fn clear_without_value_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::clear_something(RuntimeOrigin::signed(1)),
+			Error::<Test>::NoneValue
+		);
+	});
+}
+
+#[test]
+fn do_something_requires_deposit() {
+	new_test_ext().execute_with(|| {
+		// Account 3 cannot afford the storage deposit.
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(3), 42),
+			TokenError::FundsUnavailable
+		);
+	});
+}
+
+/// The migration from storage version 0 to 1 of the mock runtime.
+type MigrateV0ToV1ToLegacyOwner = MigrateV0ToV1<Test, ConstU64<LEGACY_OWNER>>;
+
+#[test]
+fn migrate_v0_to_v1_moves_the_global_value_to_its_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		StorageVersion::new(0).put::<TemplateModule>();
+		v0::Something::<Test>::put(42);
+		// The old value lives under the bare prefix of the new map.
+		let prefix = Something::<Test>::final_prefix();
+		assert_eq!(unhashed::get::<u32>(&prefix), Some(42));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 7));
+
+		MigrateV0ToV1ToLegacyOwner::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), StorageVersion::new(1));
+		assert!(!unhashed::exists(&prefix));
+		assert_eq!(
+			Something::<Test>::get(LEGACY_OWNER),
+			Some(SomethingInfo { value: 42, who: LEGACY_OWNER, block_number: 3 })
+		);
+		// Values stored per account are left alone.
+		assert_eq!(Something::<Test>::get(1).map(|info| info.value), Some(7));
+		// No deposit backs the old value, and root can still remove it.
+		assert_eq!(held_deposit(LEGACY_OWNER), 0);
+		assert_ok!(TemplateModule::remove_something(RuntimeOrigin::root(), LEGACY_OWNER));
+		assert_eq!(Something::<Test>::get(LEGACY_OWNER), None);
+	});
+}
+
+#[test]
+fn migrate_v0_to_v1_keeps_an_existing_value_of_the_owner() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<TemplateModule>();
+		v0::Something::<Test>::put(42);
+		Something::<Test>::insert(
+			LEGACY_OWNER,
+			SomethingInfo { value: 7, who: LEGACY_OWNER, block_number: 0 },
+		);
+
+		MigrateV0ToV1ToLegacyOwner::on_runtime_upgrade();
+
+		assert_eq!(v0::Something::<Test>::get(), None);
+		assert_eq!(Something::<Test>::get(LEGACY_OWNER).map(|info| info.value), Some(7));
+	});
+}
+
+#[test]
+fn migrate_v0_to_v1_only_runs_once() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<TemplateModule>();
+		MigrateV0ToV1ToLegacyOwner::on_runtime_upgrade();
+
+		// A second run is skipped, as the on-chain version is already 1.
+		v0::Something::<Test>::put(42);
+		MigrateV0ToV1ToLegacyOwner::on_runtime_upgrade();
+		assert_eq!(v0::Something::<Test>::get(), Some(42));
+		assert_eq!(Something::<Test>::get(LEGACY_OWNER), None);
+	});
+}
+
+#[test]
+fn migrate_v1_to_v2_moves_reserves_to_holds() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<TemplateModule>();
+		// Version 1 reserved the deposit instead of holding it.
+		for who in [1, 2] {
+			Something::<Test>::insert(who, SomethingInfo { value: 42, who, block_number: 0 });
+			assert_ok!(Balances::reserve(&who, SOMETHING_DEPOSIT));
+		}
+
+		MigrateV1ToV2::<Test, Balances>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), StorageVersion::new(2));
+		for who in [1, 2] {
+			assert_eq!(held_deposit(who), SOMETHING_DEPOSIT);
+			// The funds moved from the anonymous reserve to the hold.
+			assert_eq!(Balances::reserved_balance(who), SOMETHING_DEPOSIT);
+		}
+		// The migrated deposit is released like any other.
+		assert_ok!(TemplateModule::clear_something(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn migrate_v0_to_v2_leaves_the_global_value_without_deposit() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<TemplateModule>();
+		v0::Something::<Test>::put(42);
+
+		MigrateV0ToV1ToLegacyOwner::on_runtime_upgrade();
+		MigrateV1ToV2::<Test, Balances>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), StorageVersion::new(2));
+		assert_eq!(Something::<Test>::get(LEGACY_OWNER).map(|info| info.value), Some(42));
+		assert_eq!(held_deposit(LEGACY_OWNER), 0);
+	});
+}
+
+/// Set the input of the offchain worker.
+fn set_offchain_input(value: u32) {
+	sp_io::offchain::local_storage_set(
+		StorageKind::PERSISTENT,
+		OFFCHAIN_INPUT_KEY,
+		&value.encode(),
+	);
+}
+
+/// Split the transactions of an offchain worker run into the signed and the unsigned one.
+fn signed_and_unsigned(mut txs: Vec<Extrinsic>) -> (Extrinsic, Extrinsic) {
+	assert_eq!(txs.len(), 2);
+	let unsigned = txs.pop().unwrap();
+	let signed = txs.pop().unwrap();
+	assert!(unsigned.signature.is_none());
+	(signed, unsigned)
+}
+
+#[test]
+fn offchain_worker_submits_input() {
+	let (mut t, take_transactions) = new_offchain_test_ext();
+	t.execute_with(|| {
+		System::set_block_number(2);
+		set_offchain_input(7);
+		TemplateModule::offchain_worker(2);
+
+		let (signed, unsigned) = signed_and_unsigned(take_transactions());
+		assert_eq!(signed.signature, Some((OFFCHAIN_ACCOUNT, ())));
+		assert_eq!(signed.call, RuntimeCall::TemplateModule(Call::do_something { something: 7 }));
+
+		let RuntimeCall::TemplateModule(Call::submit_something_unsigned { payload, signature }) =
+			unsigned.call.clone()
+		else {
+			panic!("unexpected call: {:?}", unsigned.call)
+		};
+		assert_eq!(
+			payload,
+			SomethingPayload {
+				block_number: 2,
+				something: 7,
+				public: UintAuthorityId(OFFCHAIN_ACCOUNT)
+			}
+		);
+		assert_eq!(signature, TestSignature(OFFCHAIN_ACCOUNT, payload.encode()));
+
+		// The unsigned transaction is valid and stores the value.
+		let call = Call::submit_something_unsigned { payload, signature };
+		assert_ok!(TemplateModule::validate_unsigned(TransactionSource::Local, &call));
+		assert_ok!(unsigned.call.dispatch(RuntimeOrigin::none()));
+		assert_eq!(
+			OffchainSomething::<Test>::get(),
+			Some(SomethingInfo { value: 7, who: OFFCHAIN_ACCOUNT, block_number: 2 })
+		);
+		System::assert_last_event(
+			Event::OffchainSomethingStored { something: 7, who: OFFCHAIN_ACCOUNT }.into(),
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_clamps_input_to_bounds() {
+	let (mut t, take_transactions) = new_offchain_test_ext();
+	t.execute_with(|| {
+		System::set_block_number(1);
+		set_offchain_input(MAX_VALUE + 1);
+		TemplateModule::offchain_worker(1);
+
+		let (signed, _) = signed_and_unsigned(take_transactions());
+		assert_eq!(
+			signed.call,
+			RuntimeCall::TemplateModule(Call::do_something { something: MAX_VALUE })
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_skips_submitted_values() {
+	let (mut t, take_transactions) = new_offchain_test_ext();
+	t.execute_with(|| {
+		// Without input, nothing is submitted.
+		System::set_block_number(1);
+		TemplateModule::offchain_worker(1);
+		assert!(take_transactions().is_empty());
+
+		set_offchain_input(7);
+		TemplateModule::offchain_worker(1);
+		let (_, unsigned) = signed_and_unsigned(take_transactions());
+
+		// The signed value is only submitted once, the unsigned one until it is stored.
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		let txs = take_transactions();
+		assert_eq!(txs.len(), 1);
+		assert!(txs[0].signature.is_none());
+
+		assert_ok!(unsigned.call.dispatch(RuntimeOrigin::none()));
+		System::set_block_number(3);
+		TemplateModule::offchain_worker(3);
+		assert!(take_transactions().is_empty());
+
+		// A new input is submitted again.
+		set_offchain_input(8);
+		TemplateModule::offchain_worker(3);
+		signed_and_unsigned(take_transactions());
+	});
+}
+
+#[test]
+fn unsigned_values_must_be_signed_fresh_and_bounded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		let call = |block_number: u64, something: u32, signer: u64| -> Call<Test> {
+			let payload = SomethingPayload {
+				block_number,
+				something,
+				public: UintAuthorityId(OFFCHAIN_ACCOUNT),
+			};
+			let signature = TestSignature(signer, payload.encode());
+			Call::submit_something_unsigned { payload, signature }
+		};
+		let validate = |call: Call<Test>| {
+			TemplateModule::validate_unsigned(TransactionSource::External, &call)
+		};
+
+		assert_ok!(validate(call(5, 7, OFFCHAIN_ACCOUNT)));
+		assert_eq!(validate(call(5, 7, 2)), InvalidTransaction::BadProof.into());
+		assert_eq!(
+			validate(call(5, MAX_VALUE + 1, OFFCHAIN_ACCOUNT)),
+			InvalidTransaction::Call.into()
+		);
+		assert_eq!(validate(call(6, 7, OFFCHAIN_ACCOUNT)), InvalidTransaction::Future.into());
+
+		// Once a value is stored, older payloads cannot be replayed.
+		OffchainSomething::<Test>::put(SomethingInfo {
+			value: 7,
+			who: OFFCHAIN_ACCOUNT,
+			block_number: 4,
+		});
+		assert_eq!(validate(call(4, 7, OFFCHAIN_ACCOUNT)), InvalidTransaction::Stale.into());
+		assert_ok!(validate(call(5, 8, OFFCHAIN_ACCOUNT)));
+
+		// The call itself can only be submitted unsigned.
+		assert_noop!(
+			TemplateModule::submit_something_unsigned(
+				RuntimeOrigin::signed(1),
+				SomethingPayload { block_number: 5, something: 7, public: UintAuthorityId(1) },
+				TestSignature(1, vec![]),
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn unsigned_values_must_be_signed_by_an_authority() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		// A correctly signed payload of a key that is not an offchain authority.
+		let unknown = OFFCHAIN_ACCOUNT + 1;
+		let payload =
+			SomethingPayload { block_number: 5, something: 7, public: UintAuthorityId(unknown) };
+		let signature = TestSignature(unknown, payload.encode());
+		let call = Call::submit_something_unsigned { payload, signature };
+
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::BadProof.into()
+		);
+	});
+}
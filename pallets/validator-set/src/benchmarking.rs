@@ -0,0 +1,68 @@
+//! Benchmarking setup for pallet-validator-set
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ValidatorSet;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{
+		fungible::{Inspect, Mutate},
+		EnsureOrigin,
+	},
+	BoundedVec,
+};
+
+/// Fill the set with `n` generated validators.
+fn set_validators<T: Config>(n: u32) {
+	let validators: Vec<T::AccountId> = (0..n).map(|i| account("validator", i, 0)).collect();
+	let validators = BoundedVec::try_from(validators).expect("n is within MaxValidators; qed");
+	Validators::<T>::put(validators);
+}
+
+/// Endow `who` with enough to afford the validator bond.
+fn endow<T: Config>(who: &T::AccountId) {
+	T::Currency::set_balance(who, T::Currency::minimum_balance() + T::ValidatorBond::get());
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn add_validator() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AddRemoveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		set_validators::<T>(T::MaxValidators::get() - 1);
+		let who: T::AccountId = account("new", 0, 0);
+		endow::<T>(&who);
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert!(Validators::<T>::get().contains(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_validator() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AddRemoveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let max = T::MaxValidators::get();
+		set_validators::<T>(max);
+		// The last validator is the most expensive to look up.
+		let who: T::AccountId = account("validator", max - 1, 0);
+		endow::<T>(&who);
+		T::Currency::hold(&HoldReason::ValidatorBond.into(), &who, T::ValidatorBond::get())?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert!(!Validators::<T>::get().contains(&who));
+		assert_eq!(
+			T::Currency::balance(&who),
+			T::Currency::minimum_balance() + T::ValidatorBond::get()
+		);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(ValidatorSet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,287 @@
+//! # Validator Set Pallet
+//!
+//! Lets a privileged origin (root, or a collective once governance is in place) add and remove
+//! the validators that author blocks and vote in GRANDPA.
+//!
+//! ## Overview
+//!
+//! The pallet acts as the [`pallet_session::SessionManager`] of the runtime. Changes to the set
+//! are not applied immediately: they are handed to `pallet_session` when it plans the next
+//! session, and take effect at the session boundary after that. At that point the session pallet
+//! rotates the Aura and GRANDPA authorities to the session keys registered by each validator
+//! through `Session::set_keys`. Validators without session keys are skipped by the session pallet
+//! until they register some.
+//!
+//! Every validator has [`Config::ValidatorBond`] held from its account under
+//! [`HoldReason::ValidatorBond`] for as long as it is in the set, which bounds what an offence
+//! handler can slash from it. The bond is released in full when the validator is removed.
+//! Validators that joined a chain before bonds were introduced have none held, so offences can only
+//! disable them.
+//!
+//! Validators disabled through [`pallet_session::Pallet::disable`], e.g. by an offence handler,
+//! stay disabled for the rest of the session only: the pallet clears the disabled set whenever a
+//! new session starts.
+//!
+//! The pallet also implements [`pallet_session::historical::SessionManager`], so that it can be
+//! wrapped in [`pallet_session::historical::NoteHistoricalRoot`] to keep the session roots needed
+//! by key-ownership proofs.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use alloc::vec::Vec;
+use frame_support::{
+	sp_runtime::traits::Convert,
+	traits::{
+		fungible::{self, MutateHold},
+		tokens::Precision,
+	},
+};
+use sp_staking::SessionIndex;
+
+/// The balance type of [`Config::Currency`].
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The pallet's configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_session::Config {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin allowed to add and remove validators.
+		type AddRemoveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The maximum number of validators. Should not exceed the `MaxAuthorities` of the
+		/// consensus pallets.
+		#[pallet::constant]
+		type MaxValidators: Get<u32>;
+		/// The minimum number of validators that must remain in the set.
+		#[pallet::constant]
+		type MinValidators: Get<u32>;
+		/// The overarching hold reason, which [`HoldReason`] is part of.
+		type RuntimeHoldReason: From<HoldReason>;
+		/// The currency in which validator bonds are held.
+		type Currency: fungible::Mutate<Self::AccountId>
+			+ fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+		/// The amount held from a validator for as long as it is in the set.
+		#[pallet::constant]
+		type ValidatorBond: Get<BalanceOf<Self>>;
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The reasons for which this pallet places holds on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as the bond of a validator in [`Validators`].
+		#[codec(index = 0)]
+		ValidatorBond,
+	}
+
+	/// The accounts of the validators to use for the next planned session.
+	#[pallet::storage]
+	pub type Validators<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxValidators>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A validator was added and joins the set at a coming session boundary.
+		ValidatorAdded { who: T::AccountId },
+		/// A validator was removed and leaves the set at a coming session boundary.
+		ValidatorRemoved { who: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already a validator.
+		Duplicate,
+		/// The account is not a validator.
+		NotAValidator,
+		/// Adding the validator would exceed [`Config::MaxValidators`].
+		TooManyValidators,
+		/// Removing the validator would go below [`Config::MinValidators`].
+		TooFewValidators,
+	}
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The validators of the first sessions. If empty, `pallet_session` falls back to the
+		/// accounts in its own genesis keys.
+		///
+		/// Each validator must be endowed with at least [`Config::ValidatorBond`], which is held
+		/// as its bond.
+		pub initial_validators: Vec<T::AccountId>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let mut unique = self.initial_validators.clone();
+			unique.sort();
+			unique.dedup();
+			assert_eq!(
+				unique.len(),
+				self.initial_validators.len(),
+				"duplicate initial validators in genesis"
+			);
+
+			let validators: BoundedVec<_, T::MaxValidators> = self
+				.initial_validators
+				.clone()
+				.try_into()
+				.expect("genesis validators must not exceed `MaxValidators`");
+			for who in validators.iter() {
+				T::Currency::hold(&HoldReason::ValidatorBond.into(), who, T::ValidatorBond::get())
+					.expect("genesis validators must be endowed with `ValidatorBond`");
+			}
+			Validators::<T>::put(validators);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Add `who` to the validator set.
+		///
+		/// The new validator starts authoring once the session pallet has enacted the change and
+		/// only if it has set its session keys. [`Config::ValidatorBond`] is held from `who`, which
+		/// fails if it cannot afford it.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::add_validator())]
+		pub fn add_validator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AddRemoveOrigin::ensure_origin(origin)?;
+
+			Validators::<T>::try_mutate(|validators| {
+				ensure!(!validators.contains(&who), Error::<T>::Duplicate);
+				validators.try_push(who.clone()).map_err(|_| Error::<T>::TooManyValidators)?;
+				T::Currency::hold(&HoldReason::ValidatorBond.into(), &who, T::ValidatorBond::get())
+			})?;
+
+			Self::deposit_event(Event::ValidatorAdded { who });
+			Ok(())
+		}
+
+		/// Remove `who` from the validator set, releasing what is left of its bond.
+		///
+		/// The bond is released right away, although `who` keeps validating until the change is
+		/// enacted: offences reported from then on can only disable it.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_validator())]
+		pub fn remove_validator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AddRemoveOrigin::ensure_origin(origin)?;
+
+			Validators::<T>::try_mutate(|validators| {
+				let index =
+					validators.iter().position(|v| v == &who).ok_or(Error::<T>::NotAValidator)?;
+				ensure!(
+					validators.len() > T::MinValidators::get() as usize,
+					Error::<T>::TooFewValidators
+				);
+				validators.remove(index);
+				Ok::<_, DispatchError>(())
+			})?;
+			T::Currency::release_all(
+				&HoldReason::ValidatorBond.into(),
+				&who,
+				Precision::BestEffort,
+			)?;
+
+			Self::deposit_event(Event::ValidatorRemoved { who });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The validator ids of the current set, or `None` if no validators are configured.
+	fn validator_ids() -> Option<Vec<T::ValidatorId>> {
+		let validators = Validators::<T>::get();
+		if validators.is_empty() {
+			return None
+		}
+		Some(validators.into_iter().filter_map(T::ValidatorIdOf::convert).collect())
+	}
+}
+
+impl<T: Config> pallet_session::SessionManager<T::ValidatorId> for Pallet<T> {
+	fn new_session(_new_index: SessionIndex) -> Option<Vec<T::ValidatorId>> {
+		let validators = Self::validator_ids()?;
+
+		// Only report a new set when it differs from the active one, so that unchanged sessions
+		// do not force a new GRANDPA authority set.
+		if validators == pallet_session::Pallet::<T>::validators() {
+			return None
+		}
+		Some(validators)
+	}
+
+	fn new_session_genesis(_new_index: SessionIndex) -> Option<Vec<T::ValidatorId>> {
+		Self::validator_ids()
+	}
+
+	fn end_session(_end_index: SessionIndex) {}
+
+	fn start_session(_start_index: SessionIndex) {
+		// `pallet_session` only re-enables validators when the active set changes, but a disabled
+		// validator should get a fresh start in the next session regardless.
+		pallet_session::DisabledValidators::<T>::kill();
+	}
+}
+
+impl<T: Config + pallet_session::historical::Config>
+	pallet_session::historical::SessionManager<T::ValidatorId, T::FullIdentification> for Pallet<T>
+{
+	fn new_session(
+		new_index: SessionIndex,
+	) -> Option<Vec<(T::ValidatorId, T::FullIdentification)>> {
+		<Self as pallet_session::SessionManager<_>>::new_session(new_index)
+			.map(with_identification::<T>)
+	}
+
+	fn new_session_genesis(
+		new_index: SessionIndex,
+	) -> Option<Vec<(T::ValidatorId, T::FullIdentification)>> {
+		<Self as pallet_session::SessionManager<_>>::new_session_genesis(new_index)
+			.map(with_identification::<T>)
+	}
+
+	fn end_session(end_index: SessionIndex) {
+		<Self as pallet_session::SessionManager<_>>::end_session(end_index)
+	}
+
+	fn start_session(start_index: SessionIndex) {
+		<Self as pallet_session::SessionManager<_>>::start_session(start_index)
+	}
+}
+
+fn with_identification<T: pallet_session::historical::Config>(
+	validators: Vec<T::ValidatorId>,
+) -> Vec<(T::ValidatorId, T::FullIdentification)> {
+	validators
+		.into_iter()
+		.filter_map(|v| T::FullIdentificationOf::convert(v.clone()).map(|id| (v, id)))
+		.collect()
+}
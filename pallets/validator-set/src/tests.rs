@@ -0,0 +1,162 @@
+use crate::{mock::*, Error, Event, HoldReason, Validators};
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use pallet_session::SessionManager;
+use sp_runtime::{DispatchError, TokenError};
+
+/// The bond `who` currently has on hold.
+fn held_bond(who: u64) -> u64 {
+	Balances::balance_on_hold(&RuntimeHoldReason::ValidatorSet(HoldReason::ValidatorBond), &who)
+}
+
+#[test]
+fn genesis_validators_are_the_first_session_set() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Validators::<Test>::get().into_inner(), vec![1, 2, 3]);
+		assert_eq!(Session::validators(), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn root_can_add_a_validator() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+		assert_eq!(Validators::<Test>::get().into_inner(), vec![1, 2, 3, 4]);
+		System::assert_last_event(Event::ValidatorAdded { who: 4 }.into());
+	});
+}
+
+#[test]
+fn root_can_remove_a_validator() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), 2));
+		assert_eq!(Validators::<Test>::get().into_inner(), vec![1, 3]);
+		System::assert_last_event(Event::ValidatorRemoved { who: 2 }.into());
+	});
+}
+
+#[test]
+fn validators_have_their_bond_held() {
+	new_test_ext().execute_with(|| {
+		for who in 1..=3 {
+			assert_eq!(held_bond(who), VALIDATOR_BOND);
+			assert_eq!(Balances::free_balance(who), ENDOWMENT - VALIDATOR_BOND);
+		}
+		assert_eq!(held_bond(4), 0);
+
+		assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+		assert_eq!(held_bond(4), VALIDATOR_BOND);
+
+		assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), 2));
+		assert_eq!(held_bond(2), 0);
+		assert_eq!(Balances::free_balance(2), ENDOWMENT);
+	});
+}
+
+#[test]
+fn cannot_add_a_validator_without_its_bond() {
+	new_test_ext().execute_with(|| {
+		// Account 6 is not endowed.
+		assert_noop!(
+			ValidatorSet::add_validator(RuntimeOrigin::root(), 6),
+			TokenError::FundsUnavailable
+		);
+	});
+}
+
+#[test]
+fn only_the_configured_origin_can_change_the_set() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ValidatorSet::add_validator(RuntimeOrigin::signed(1), 4),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ValidatorSet::remove_validator(RuntimeOrigin::signed(1), 2),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn cannot_add_a_validator_twice() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ValidatorSet::add_validator(RuntimeOrigin::root(), 1),
+			Error::<Test>::Duplicate
+		);
+	});
+}
+
+#[test]
+fn cannot_remove_an_unknown_validator() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ValidatorSet::remove_validator(RuntimeOrigin::root(), 4),
+			Error::<Test>::NotAValidator
+		);
+	});
+}
+
+#[test]
+fn set_size_is_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+		assert_eq!(Validators::<Test>::get().len(), MAX_VALIDATORS as usize);
+		assert_noop!(
+			ValidatorSet::add_validator(RuntimeOrigin::root(), 5),
+			Error::<Test>::TooManyValidators
+		);
+
+		for who in 2..=4 {
+			assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), who));
+		}
+		assert_eq!(Validators::<Test>::get().len(), MIN_VALIDATORS as usize);
+		assert_noop!(
+			ValidatorSet::remove_validator(RuntimeOrigin::root(), 1),
+			Error::<Test>::TooFewValidators
+		);
+	});
+}
+
+#[test]
+fn changes_take_effect_at_the_second_session_boundary() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+		assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), 1));
+
+		// The change is queued when the next session is planned...
+		Session::rotate_session();
+		assert_eq!(Session::validators(), vec![1, 2, 3]);
+		assert_eq!(
+			Session::queued_keys().into_iter().map(|(v, _)| v).collect::<Vec<_>>(),
+			vec![2, 3, 4]
+		);
+
+		// ...and enacted at the following boundary.
+		Session::rotate_session();
+		assert_eq!(Session::validators(), vec![2, 3, 4]);
+	});
+}
+
+#[test]
+fn disabled_validators_are_reenabled_at_the_next_session() {
+	new_test_ext().execute_with(|| {
+		assert!(Session::disable(&2));
+		assert_eq!(Session::disabled_validators(), vec![1]);
+
+		// The set itself is unchanged, yet the next session starts with everyone enabled.
+		Session::rotate_session();
+		assert_eq!(Session::validators(), vec![1, 2, 3]);
+		assert!(Session::disabled_validators().is_empty());
+	});
+}
+
+#[test]
+fn unchanged_set_does_not_signal_a_new_session_set() {
+	new_test_ext().execute_with(|| {
+		Session::rotate_session();
+		Session::rotate_session();
+		assert_eq!(Session::validators(), vec![1, 2, 3]);
+		assert_eq!(<ValidatorSet as SessionManager<u64>>::new_session(3), None);
+	});
+}
@@ -0,0 +1,43 @@
+;; A contract that forwards its input to the chain extension of the runtime.
+;;
+;; The input is the id of the chain extension function as a little-endian `u32`, followed by the
+;; input of the function. The contract returns the status code of the function as a
+;; little-endian `u32`, followed by the output of the function.
+;;
+;; `template_extension.wasm` is built from this file with `wat2wasm template_extension.wat`.
+(module
+	(import "seal0" "input" (func $input (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "seal0" "call_chain_extension"
+		(func $call_chain_extension (param i32 i32 i32 i32 i32) (result i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) length of the input
+	;; [4, 8) length of the output
+	;; [8, 264) input: function id, then the input of the function
+	;; [264, 268) status code
+	;; [268, 524) output of the function
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(i32.store (i32.const 0) (i32.const 256))
+		(call $input (i32.const 8) (i32.const 0))
+		(i32.store (i32.const 4) (i32.const 256))
+		(i32.store
+			(i32.const 264)
+			(call $call_chain_extension
+				(i32.load (i32.const 8))
+				(i32.const 12)
+				(i32.sub (i32.load (i32.const 0)) (i32.const 4))
+				(i32.const 268)
+				(i32.const 4)
+			)
+		)
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 264)
+			(i32.add (i32.load (i32.const 4)) (i32.const 4))
+		)
+	)
+)
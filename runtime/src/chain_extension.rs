@@ -0,0 +1,77 @@
+//! The chain extension of `pallet_contracts`, which lets contracts use `pallet_template`.
+//!
+//! A contract calls a function of the extension through `call_chain_extension`, with the
+//! function id as `id` and the SCALE-encoded input of the function as input. Every function
+//! returns one of the [`status`] codes. In ink!, declare the functions with
+//! `#[ink::chain_extension]` and map the status codes through `FromStatusCode`.
+
+use crate::{AccountId, Runtime, RuntimeOrigin, TemplateModule};
+use codec::Encode;
+use frame_support::traits::Get;
+use pallet_contracts::chain_extension::{ChainExtension, Environment, Ext, InitState, RetVal};
+use pallet_template::{Error, WeightInfo};
+use sp_runtime::DispatchError;
+
+/// Read the value an account stores in `pallet_template::Something`.
+///
+/// Takes the `AccountId` of the account and outputs an `Option<u32>`.
+pub const GET_SOMETHING: u16 = 0;
+
+/// Store a value for the calling contract, as `pallet_template::Pallet::do_something` does for a
+/// signed caller. The deposit is held from the balance of the contract.
+///
+/// Takes the `u32` to store and outputs nothing.
+pub const SET_SOMETHING: u16 = 1;
+
+/// The status codes returned by the functions of [`TemplateExtension`].
+///
+/// Errors without a status code, e.g. a contract that cannot afford the deposit, trap the
+/// contract.
+pub mod status {
+	/// The function succeeded.
+	pub const SUCCESS: u32 = 0;
+	/// The value is larger than the template pallet's `MaxValue`.
+	pub const VALUE_TOO_LARGE: u32 = 1;
+	/// The value is smaller than the template pallet's `MinValue`.
+	pub const VALUE_TOO_SMALL: u32 = 2;
+	/// The contract already stores the value.
+	pub const UNCHANGED: u32 = 3;
+}
+
+/// Lets contracts read and write `pallet_template::Something`.
+#[derive(Default)]
+pub struct TemplateExtension;
+
+impl ChainExtension<Runtime> for TemplateExtension {
+	fn call<E: Ext<T = Runtime>>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError> {
+		let mut env = env.buf_in_buf_out();
+		match env.func_id() {
+			GET_SOMETHING => {
+				env.charge_weight(<Runtime as frame_system::Config>::DbWeight::get().reads(1))?;
+				let who: AccountId = env.read_as()?;
+				let value = pallet_template::Something::<Runtime>::get(&who).map(|info| info.value);
+				env.write(&value.encode(), false, None)?;
+				Ok(RetVal::Converging(status::SUCCESS))
+			},
+			SET_SOMETHING => {
+				env.charge_weight(<Runtime as pallet_template::Config>::WeightInfo::do_something())?;
+				let value: u32 = env.read_as()?;
+				let contract = env.ext().address().clone();
+				match TemplateModule::do_something(RuntimeOrigin::signed(contract), value) {
+					Ok(()) => Ok(RetVal::Converging(status::SUCCESS)),
+					Err(e) if e == Error::<Runtime>::ValueTooLarge.into() =>
+						Ok(RetVal::Converging(status::VALUE_TOO_LARGE)),
+					Err(e) if e == Error::<Runtime>::ValueTooSmall.into() =>
+						Ok(RetVal::Converging(status::VALUE_TOO_SMALL)),
+					Err(e) if e == Error::<Runtime>::Unchanged.into() =>
+						Ok(RetVal::Converging(status::UNCHANGED)),
+					Err(e) => Err(e),
+				}
+			},
+			_ => Err(DispatchError::Other("unknown chain extension function")),
+		}
+	}
+}
@@ -0,0 +1,214 @@
+//! Ethereum compatibility through Frontier, compiled in with the `evm` feature.
+//!
+//! `pallet_evm` runs EVM contracts and `pallet_ethereum` accepts Ethereum transactions as
+//! self-contained extrinsics: they carry their own signature and are applied with an
+//! `EthereumTransaction` origin instead of a signed one.
+//!
+//! Ethereum addresses are 20-byte `H160`s, while the accounts of the runtime are 32-byte
+//! `AccountId`s. They relate as follows:
+//!
+//! - The balance and nonce of an address are those of the account [`evm_account_id`], the BLAKE2
+//!   hash of `b"evm:"` followed by the address. An Ethereum account is thus funded by a regular
+//!   transfer to that account.
+//! - An account calling `evm.call`, `evm.create` or `evm.withdraw` directly acts as the address
+//!   [`evm_address`], the first 20 bytes of its `AccountId`.
+//!
+//! Balances are not rescaled: one unit of the smallest denomination is one wei, so Ethereum
+//! wallets show balances with 18 decimals instead of [`TOKEN_DECIMALS`](crate::currency).
+
+use crate::{
+	AccountId, Aura, Balances, BlockWeights, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+	Timestamp, UncheckedExtrinsic, NORMAL_DISPATCH_RATIO,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, ConstU64, FindAuthor},
+	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+};
+use pallet_ethereum::PostLogContent;
+use pallet_evm::{
+	AddressMapping, EnsureAddressTruncated, FeeCalculator, FixedGasWeightMapping,
+	HashedAddressMapping,
+};
+use sp_core::{H160, U256};
+use sp_runtime::{
+	traits::{BlakeTwo256, Block as BlockT, DispatchInfoOf, Dispatchable, PostDispatchInfoOf},
+	transaction_validity::{TransactionValidity, TransactionValidityError},
+	ConsensusEngineId,
+};
+use sp_std::marker::PhantomData;
+
+/// The EVM gas executed per second of block weight.
+pub const GAS_PER_SECOND: u64 = 40_000_000;
+
+/// The weight of one unit of gas.
+pub const WEIGHT_PER_GAS: u64 = WEIGHT_REF_TIME_PER_SECOND / GAS_PER_SECOND;
+
+parameter_types! {
+	/// The EIP-155 chain id, which Ethereum transactions sign over.
+	pub const ChainId: u64 = 42;
+	/// The gas of the weight available to normal extrinsics.
+	pub BlockGasLimit: U256 = U256::from(
+		NORMAL_DISPATCH_RATIO * BlockWeights::get().max_block.ref_time() / WEIGHT_PER_GAS,
+	);
+	pub WeightPerGas: Weight = Weight::from_parts(WEIGHT_PER_GAS, 0);
+	pub PrecompilesValue: () = ();
+	pub const PostBlockAndTxnHashes: PostLogContent = PostLogContent::BlockAndTxnHashes;
+}
+
+/// An Ethereum account endowed on the development chains, with the well-known private key
+/// `0x5fb92d6e98884f76de468fa3f6278f8807c48bebc13595d45af5bdc4da702133`.
+///
+/// Its address is `0xf24FF3a9CF04c71Dbc94D0b566f7A27B94566cac`.
+pub const DEV_ACCOUNT: [u8; 20] = [
+	0xf2, 0x4f, 0xf3, 0xa9, 0xcf, 0x04, 0xc7, 0x1d, 0xbc, 0x94, 0xd0, 0xb5, 0x66, 0xf7, 0xa2, 0x7b,
+	0x94, 0x56, 0x6c, 0xac,
+];
+
+/// The account holding the balance and nonce of the Ethereum address `address`.
+pub fn evm_account_id(address: H160) -> AccountId {
+	HashedAddressMapping::<BlakeTwo256>::into_account_id(address)
+}
+
+/// The Ethereum address `who` acts as when calling `pallet_evm` directly.
+pub fn evm_address(who: &AccountId) -> H160 {
+	H160::from_slice(&AsRef::<[u8; 32]>::as_ref(who)[..20])
+}
+
+/// Prices gas like the weight it is converted to, so that the EVM and regular extrinsics cost
+/// the same for the same weight. The fee multiplier is not applied.
+pub struct WeightFeeGasPrice;
+
+impl FeeCalculator for WeightFeeGasPrice {
+	fn min_gas_price() -> (U256, Weight) {
+		use frame_support::weights::WeightToFee as _;
+		let price = crate::WeightToFee::weight_to_fee(&WeightPerGas::get());
+		(price.into(), Weight::zero())
+	}
+}
+
+/// Finds the block author, i.e. the `COINBASE` of the EVM, as the [`evm_address`] of the account
+/// found by `F`.
+pub struct FindAuthorTruncated<F>(PhantomData<F>);
+
+impl<F: FindAuthor<AccountId>> FindAuthor<H160> for FindAuthorTruncated<F> {
+	fn find_author<'a, I>(digests: I) -> Option<H160>
+	where
+		I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+	{
+		F::find_author(digests).map(|who| evm_address(&who))
+	}
+}
+
+impl pallet_evm::Config for Runtime {
+	type FeeCalculator = WeightFeeGasPrice;
+	type GasWeightMapping = FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type BlockHashMapping = pallet_ethereum::EthereumBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressTruncated;
+	type WithdrawOrigin = EnsureAddressTruncated;
+	type AddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type PrecompilesType = ();
+	type PrecompilesValue = PrecompilesValue;
+	type ChainId = ChainId;
+	type BlockGasLimit = BlockGasLimit;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type FindAuthor = FindAuthorTruncated<pallet_session::FindAccountFromAuthorIndex<Self, Aura>>;
+	// A solochain has no proof size limit to account gas against.
+	type GasLimitPovSizeRatio = ConstU64<0>;
+	type SuicideQuickClearLimit = ConstU32<0>;
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Self>;
+}
+
+impl pallet_ethereum::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type StateRoot = pallet_ethereum::IntermediateStateRoot<Self>;
+	type PostLogContent = PostBlockAndTxnHashes;
+	type ExtraDataLength = ConstU32<30>;
+}
+
+impl fp_self_contained::SelfContainedCall for RuntimeCall {
+	type SignedInfo = H160;
+
+	fn is_self_contained(&self) -> bool {
+		match self {
+			RuntimeCall::Ethereum(call) => call.is_self_contained(),
+			_ => false,
+		}
+	}
+
+	fn check_self_contained(&self) -> Option<Result<Self::SignedInfo, TransactionValidityError>> {
+		match self {
+			RuntimeCall::Ethereum(call) => call.check_self_contained(),
+			_ => None,
+		}
+	}
+
+	fn validate_self_contained(
+		&self,
+		info: &Self::SignedInfo,
+		dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		len: usize,
+	) -> Option<TransactionValidity> {
+		match self {
+			RuntimeCall::Ethereum(call) => call.validate_self_contained(info, dispatch_info, len),
+			_ => None,
+		}
+	}
+
+	fn pre_dispatch_self_contained(
+		&self,
+		info: &Self::SignedInfo,
+		dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		len: usize,
+	) -> Option<Result<(), TransactionValidityError>> {
+		match self {
+			RuntimeCall::Ethereum(call) =>
+				call.pre_dispatch_self_contained(info, dispatch_info, len),
+			_ => None,
+		}
+	}
+
+	fn apply_self_contained(
+		self,
+		info: Self::SignedInfo,
+	) -> Option<sp_runtime::DispatchResultWithInfo<PostDispatchInfoOf<Self>>> {
+		match self {
+			call @ RuntimeCall::Ethereum(pallet_ethereum::Call::transact { .. }) =>
+				Some(call.dispatch(RuntimeOrigin::from(
+					pallet_ethereum::RawOrigin::EthereumTransaction(info),
+				))),
+			_ => None,
+		}
+	}
+}
+
+/// Wraps the Ethereum transactions submitted through `eth_sendRawTransaction` into extrinsics of
+/// the block type `B`.
+pub struct TransactionConverter<B>(PhantomData<B>);
+
+impl<B> Default for TransactionConverter<B> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<B: BlockT> fp_rpc::ConvertTransaction<<B as BlockT>::Extrinsic> for TransactionConverter<B> {
+	fn convert_transaction(
+		&self,
+		transaction: pallet_ethereum::Transaction,
+	) -> <B as BlockT>::Extrinsic {
+		let extrinsic = UncheckedExtrinsic::new_unsigned(
+			pallet_ethereum::Call::<Runtime>::transact { transaction }.into(),
+		);
+		let encoded = extrinsic.encode();
+		<B as BlockT>::Extrinsic::decode(&mut &encoded[..])
+			.expect("Encoded extrinsic is always valid")
+	}
+}
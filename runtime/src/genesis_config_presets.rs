@@ -0,0 +1,197 @@
+//! The named genesis presets of the runtime.
+//!
+//! A preset is a JSON patch on top of the default [`crate::RuntimeGenesisConfig`], in the format
+//! used by the [`sp_genesis_builder::GenesisBuilder`] runtime API. The node builds its `dev` and
+//! `local` chain specs from these presets, so the genesis of the template chains is defined next to
+//! the runtime it configures.
+
+use crate::{opaque::SessionKeys, AccountId, BlockNumber, DAYS, UNIT};
+use hex_literal::hex;
+use serde_json::Value;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
+use sp_std::prelude::*;
+
+/// A single-validator development chain run by Alice.
+pub const DEV_RUNTIME_PRESET: &str = "dev";
+
+/// A local testnet run by Alice and Bob.
+pub const LOCAL_TESTNET_RUNTIME_PRESET: &str = "local_testnet";
+
+/// The key-independent part of a live network's genesis: it endows no development account, and the
+/// authorities, sudo key and endowments have to be patched in on top of it.
+pub const STAGING_RUNTIME_PRESET: &str = "staging";
+
+/// The balance of every endowed account. Balances in the JSON patch are `u64`, which this fits.
+const ENDOWMENT: u64 = (1_000_000 * UNIT) as u64;
+
+/// The validator account and the Aura and GRANDPA keys of a development authority.
+fn authority_keys(sr: Sr25519Keyring, ed: Ed25519Keyring) -> (AccountId, AuraId, GrandpaId) {
+	(sr.to_account_id(), sr.public().into(), ed.public().into())
+}
+
+/// The account of the `//stash` key of `key`, e.g. of `//Alice//stash` for
+/// [`Sr25519Keyring::Alice`].
+///
+/// The keyring of this SDK version has no stash keys, and deriving them needs `full_crypto`, which
+/// the wasm runtime is built without, so their public keys are spelled out.
+fn stash_account(key: Sr25519Keyring) -> AccountId {
+	let public = match key {
+		Sr25519Keyring::Alice =>
+			hex!("be5ddb1579b72e84524fc29e78609e3caf42e85aa118ebfe0b0ad404b5bdd25f"),
+		Sr25519Keyring::Bob =>
+			hex!("fe65717dad0447d715f660a0a58411de509b42e6efb8375f562f58a554d5860e"),
+		Sr25519Keyring::Charlie =>
+			hex!("1e07379407fecc4b89eb7dbd287c2c781cfb1907a96947a3eb18e4f8e7198625"),
+		Sr25519Keyring::Dave =>
+			hex!("e860f1b1c7227f7c22602f53f15af80747814dffd839719731ee3bba6edc126c"),
+		Sr25519Keyring::Eve =>
+			hex!("8ac59e11963af19174d0b94d5d78041c233f55d2e19324665bafdfb62925af2d"),
+		Sr25519Keyring::Ferdie =>
+			hex!("101191192fc877c24d725b337120fa3edc63d227bbc92705db1e2cb65f56981a"),
+		_ => panic!("only the well-known development accounts have stash accounts"),
+	};
+	public.into()
+}
+
+/// The accounts of `keys` and of their `//stash` keys, followed with the `evm` feature by the
+/// account of [`DEV_ACCOUNT`](crate::evm::DEV_ACCOUNT), for Ethereum tooling to use.
+fn dev_accounts(keys: impl IntoIterator<Item = Sr25519Keyring> + Clone) -> Vec<AccountId> {
+	let accounts = keys
+		.clone()
+		.into_iter()
+		.map(|k| k.to_account_id())
+		.chain(keys.into_iter().map(stash_account));
+	#[cfg(feature = "evm")]
+	let accounts = accounts.chain(Some(crate::evm::evm_account_id(crate::evm::DEV_ACCOUNT.into())));
+	accounts.collect()
+}
+
+/// Configure initial storage state for FRAME modules.
+///
+/// Each entry of `vesting` is `(who, start, length, liquid)`: of the endowed balance of `who`, all
+/// but `liquid` is locked and unlocks linearly over `length` blocks from block `start` on. `who`
+/// must also be one of the `endowed_accounts`, as must the owners of the `template_values`.
+fn testnet_genesis(
+	initial_authorities: Vec<(AccountId, AuraId, GrandpaId)>,
+	root_key: AccountId,
+	council_members: Vec<AccountId>,
+	endowed_accounts: Vec<AccountId>,
+	vesting: Vec<(AccountId, BlockNumber, BlockNumber, u64)>,
+	template_values: Vec<(AccountId, u32)>,
+) -> Value {
+	serde_json::json!({
+		"balances": {
+			"balances": endowed_accounts.into_iter().map(|k| (k, ENDOWMENT)).collect::<Vec<_>>(),
+		},
+		"vesting": {
+			"vesting": vesting,
+		},
+		"validatorSet": {
+			"initialValidators": initial_authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>(),
+		},
+		// The Aura and GRANDPA authorities are initialized by the session pallet from these keys.
+		"session": {
+			"keys": initial_authorities
+				.into_iter()
+				.map(|(account, aura, grandpa)| {
+					(account.clone(), account, SessionKeys { aura, grandpa })
+				})
+				.collect::<Vec<_>>(),
+		},
+		"council": {
+			"members": council_members,
+		},
+		"sudo": {
+			// Assign network admin rights.
+			"key": Some(root_key),
+		},
+		"templateModule": {
+			"something": template_values,
+		},
+	})
+}
+
+fn development_config_genesis() -> Value {
+	testnet_genesis(
+		// Initial PoA authorities
+		vec![authority_keys(Sr25519Keyring::Alice, Ed25519Keyring::Alice)],
+		// Sudo account
+		Sr25519Keyring::Alice.to_account_id(),
+		// Council members
+		vec![Sr25519Keyring::Alice.to_account_id(), Sr25519Keyring::Bob.to_account_id()],
+		// Pre-funded accounts
+		dev_accounts([Sr25519Keyring::Alice, Sr25519Keyring::Bob]),
+		// Vesting schedules
+		vec![],
+		// Initial `pallet_template` values
+		vec![(Sr25519Keyring::Alice.to_account_id(), 42)],
+	)
+}
+
+fn local_testnet_genesis() -> Value {
+	testnet_genesis(
+		// Initial PoA authorities
+		vec![
+			authority_keys(Sr25519Keyring::Alice, Ed25519Keyring::Alice),
+			authority_keys(Sr25519Keyring::Bob, Ed25519Keyring::Bob),
+		],
+		// Sudo account
+		Sr25519Keyring::Alice.to_account_id(),
+		// Council members
+		vec![
+			Sr25519Keyring::Alice.to_account_id(),
+			Sr25519Keyring::Bob.to_account_id(),
+			Sr25519Keyring::Charlie.to_account_id(),
+		],
+		// Pre-funded accounts
+		dev_accounts([
+			Sr25519Keyring::Alice,
+			Sr25519Keyring::Bob,
+			Sr25519Keyring::Charlie,
+			Sr25519Keyring::Dave,
+			Sr25519Keyring::Eve,
+			Sr25519Keyring::Ferdie,
+		]),
+		// Vesting schedules: the stashes of Dave, Eve and Ferdie start with a sixteenth of their
+		// balance liquid and unlock the rest linearly over 30 days.
+		[Sr25519Keyring::Dave, Sr25519Keyring::Eve, Sr25519Keyring::Ferdie]
+			.into_iter()
+			.map(|k| (stash_account(k), 0, 30 * DAYS, ENDOWMENT >> 4))
+			.collect(),
+		// Initial `pallet_template` values
+		vec![(Sr25519Keyring::Alice.to_account_id(), 42)],
+	)
+}
+
+fn staging_genesis() -> Value {
+	serde_json::json!({
+		"balances": {
+			"balances": Vec::<(AccountId, u64)>::new(),
+		},
+		"council": {
+			"members": Vec::<AccountId>::new(),
+		},
+	})
+}
+
+/// The JSON patch of the preset `id`, or `None` if there is no such preset.
+pub fn get_preset(id: &str) -> Option<Vec<u8>> {
+	let patch = match id {
+		DEV_RUNTIME_PRESET => development_config_genesis(),
+		LOCAL_TESTNET_RUNTIME_PRESET => local_testnet_genesis(),
+		STAGING_RUNTIME_PRESET => staging_genesis(),
+		_ => return None,
+	};
+	Some(
+		serde_json::to_string(&patch)
+			.expect("serialization to json is expected to work; qed")
+			.into_bytes(),
+	)
+}
+
+/// The names of all presets.
+pub fn preset_names() -> Vec<&'static str> {
+	vec![DEV_RUNTIME_PRESET, LOCAL_TESTNET_RUNTIME_PRESET, STAGING_RUNTIME_PRESET]
+}
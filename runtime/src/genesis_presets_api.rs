@@ -0,0 +1,18 @@
+//! Runtime API to read the named genesis presets of [`crate::genesis_config_presets`].
+//!
+//! The `GenesisBuilder` API of this SDK version has no preset calls, so this API exposes the
+//! presets to clients that only have the runtime blob, e.g. to build a chain spec from it.
+
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// The API to read the named genesis presets of the runtime.
+	pub trait GenesisPresetsApi {
+		/// The JSON patch of the preset `id` on top of the default genesis config, or `None` if
+		/// there is no such preset.
+		fn get_preset(id: Vec<u8>) -> Option<Vec<u8>>;
+
+		/// The names of all presets.
+		fn preset_names() -> Vec<Vec<u8>>;
+	}
+}
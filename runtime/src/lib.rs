@@ -3,47 +3,82 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use codec::{Decode, Encode, MaxEncodedLen};
 use pallet_grandpa::AuthorityId as GrandpaId;
+use pallet_session::historical::{self as pallet_session_historical, IdentificationTuple};
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify},
-	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	traits::{
+		BlakeTwo256, Block as BlockT, Bounded, ConvertInto, DispatchInfoOf, IdentifyAccount,
+		IdentityLookup, NumberFor, PostDispatchInfoOf, Verify, Zero,
+	},
+	transaction_validity::{
+		InvalidTransaction, TransactionSource, TransactionValidity, TransactionValidityError,
+	},
+	ApplyExtrinsicResult, MultiSignature, MultiSigner, RuntimeDebug, SaturatedConversion,
+};
+use sp_staking::{
+	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
+	SessionIndex,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
-use frame_support::genesis_builder_helper::{build_config, create_default_config};
 pub use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{
-		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
-		StorageInfo,
+		fungible::HoldConsideration,
+		tokens::{pay::PayFromAccount, UnityAssetBalanceConversion},
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Contains,
+		Currency, EitherOfDiverse, EnsureWithSuccess, EqualPrivilegeOnly, Imbalance,
+		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, OnUnbalanced, Randomness,
+		StorageInfo, WithdrawReasons,
 	},
 	weights::{
 		constants::{
 			BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND,
 		},
-		IdentityFee, Weight,
+		ConstantMultiplier, Weight, WeightToFeeCoefficient, WeightToFeeCoefficients,
+		WeightToFeePolynomial,
+	},
+	PalletId, StorageValue,
+};
+use frame_support::{
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
+	genesis_builder_helper::{build_config, create_default_config},
+	instances::Instance1,
+	traits::{
+		fungible::{BalancedHold, InspectHold, MutateHold},
+		fungibles::{self, Balanced},
+		tokens::ConversionToAssetBalance,
+		Get,
 	},
-	StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_asset_tx_payment::{FungiblesAdapter, HandleCredit, OnChargeAssetTransaction};
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
-use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
+use pallet_transaction_payment::{CurrencyAdapter, Multiplier, TargetedFeeAdjustment};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{FixedPointNumber, Perbill, Permill, Perquintill};
 
 /// Import the template pallet.
 pub use pallet_template;
 
+pub mod chain_extension;
+#[cfg(feature = "evm")]
+pub mod evm;
+pub mod genesis_config_presets;
+pub mod genesis_presets_api;
+pub mod scheduler_api;
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -63,6 +98,9 @@ pub type Nonce = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// An event as recorded in `frame_system::Events`.
+pub type EventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -99,10 +137,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -123,6 +161,29 @@ pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
 pub const HOURS: BlockNumber = MINUTES * 60;
 pub const DAYS: BlockNumber = HOURS * 24;
 
+/// The native token of the chain and its denominations.
+pub mod currency {
+	use super::Balance;
+
+	/// The ticker of the native token, as shown by wallets.
+	pub const TOKEN_SYMBOL: &str = "UNIT";
+	/// The number of decimals wallets display balances with.
+	pub const TOKEN_DECIMALS: u8 = 12;
+
+	/// One token, in the smallest denomination balances are stored in.
+	pub const UNIT: Balance = 10u128.pow(TOKEN_DECIMALS as u32);
+	pub const MILLI_UNIT: Balance = UNIT / 1_000;
+	pub const MICRO_UNIT: Balance = MILLI_UNIT / 1_000;
+
+	/// Existential deposit.
+	pub const EXISTENTIAL_DEPOSIT: Balance = MILLI_UNIT;
+}
+
+pub use currency::{EXISTENTIAL_DEPOSIT, MICRO_UNIT, MILLI_UNIT, UNIT};
+
+/// The prefix of the SS58 addresses of this chain.
+pub const SS58_PREFIX: u16 = 42;
+
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
 pub fn native_version() -> NativeVersion {
@@ -142,7 +203,7 @@ parameter_types! {
 		);
 	pub BlockLength: frame_system::limits::BlockLength = frame_system::limits::BlockLength
 		::max_with_normal_ratio(5 * 1024 * 1024, NORMAL_DISPATCH_RATIO);
-	pub const SS58Prefix: u8 = 42;
+	pub const SS58Prefix: u16 = SS58_PREFIX;
 }
 
 /// The default types are being injected by [`derive_impl`](`frame_support::derive_impl`) from
@@ -177,7 +238,8 @@ impl frame_system::Config for Runtime {
 
 impl pallet_aura::Config for Runtime {
 	type AuthorityId = AuraId;
-	type DisabledValidators = ();
+	// Authors disabled by an offence are not allowed to author in their slots.
+	type DisabledValidators = Session;
 	type MaxAuthorities = ConstU32<32>;
 	type AllowMultipleBlocksPerSlot = ConstBool<false>;
 
@@ -185,16 +247,251 @@ impl pallet_aura::Config for Runtime {
 	type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Runtime>;
 }
 
+parameter_types! {
+	/// The number of blocks in a session.
+	pub const SessionPeriod: BlockNumber = HOURS;
+	pub const SessionOffset: BlockNumber = 0;
+	/// The number of sessions for which GRANDPA equivocations can still be reported.
+	pub const MaxSetIdSessionEntries: u64 = 24 * 7;
+	/// The number of blocks for which an equivocation report stays valid in the pool.
+	pub const ReportLongevity: u64 = MaxSetIdSessionEntries::get() * SessionPeriod::get() as u64;
+}
+
 impl pallet_grandpa::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 
 	type WeightInfo = ();
 	type MaxAuthorities = ConstU32<32>;
 	type MaxNominators = ConstU32<0>;
-	type MaxSetIdSessionEntries = ConstU64<0>;
+	type MaxSetIdSessionEntries = MaxSetIdSessionEntries;
+
+	type KeyOwnerProof = <Historical as KeyOwnerProofSystem<(KeyTypeId, GrandpaId)>>::Proof;
+	type EquivocationReportSystem =
+		pallet_grandpa::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
+}
+
+impl pallet_session::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorId = AccountId;
+	type ValidatorIdOf = ConvertInto;
+	type ShouldEndSession = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type NextSessionRotation = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, ValidatorSet>;
+	type SessionHandler =
+		<opaque::SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
+	type Keys = opaque::SessionKeys;
+	type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_session::historical::Config for Runtime {
+	type FullIdentification = AccountId;
+	type FullIdentificationOf = ConvertInto;
+}
+
+parameter_types! {
+	/// The most a validator can lose to offences, held for as long as it is in the set.
+	pub const ValidatorBond: Balance = 100 * UNIT;
+}
+
+impl pallet_validator_set::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddRemoveOrigin = EnsureRootOrHalfCouncil;
+	type MaxValidators = ConstU32<32>;
+	type MinValidators = ConstU32<1>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type ValidatorBond = ValidatorBond;
+	type WeightInfo = pallet_validator_set::weights::SubstrateWeight<Runtime>;
+}
+
+/// Hands the authorities of a chain started before `Session` and `ValidatorSet` over to them.
+///
+/// `Session` rotates the Aura and GRANDPA authorities to the session keys of the validators in
+/// `ValidatorSet`, neither of which an upgraded chain has, so the first rotation would install an
+/// empty authority set and stop block production. This makes the current authorities the
+/// validators, registers their keys as their session keys and holds their bond from those that
+/// can afford it; the others stay in the set without a bond.
+///
+/// The authorities are paired as the template's genesis sets them: the account of each validator
+/// is its Aura key, and the Aura and GRANDPA authorities are listed in the same order. Chains whose
+/// session pallet already has validators, such as those that started with it at genesis, are left
+/// alone, so the migration can stay in [`Migrations`].
+pub struct InitializeSession;
+
+impl frame_support::traits::OnRuntimeUpgrade for InitializeSession {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		Ok(pallet_session::Validators::<Runtime>::get().is_empty().encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		use pallet_session::weights::WeightInfo;
+
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		if !pallet_session::Validators::<Runtime>::get().is_empty() {
+			return db_weight.reads(1)
+		}
+
+		let aura = Aura::authorities();
+		let grandpa = Grandpa::grandpa_authorities();
+		if aura.len() != grandpa.len() {
+			log::error!(
+				target: "runtime::session",
+				"Cannot pair {} Aura with {} GRANDPA authorities, leaving the session pallet empty",
+				aura.len(),
+				grandpa.len(),
+			);
+			return db_weight.reads(3)
+		}
+
+		let mut validators = Vec::with_capacity(aura.len());
+		let mut queued_keys = Vec::with_capacity(aura.len());
+		for (aura, (grandpa, _)) in aura.into_iter().zip(grandpa) {
+			let who =
+				MultiSigner::from(sp_core::sr25519::Public::from(aura.clone())).into_account();
+			let keys = opaque::SessionKeys { aura, grandpa };
+			if let Err(e) =
+				Session::set_keys(RuntimeOrigin::signed(who.clone()), keys.clone(), Vec::new())
+			{
+				log::error!(target: "runtime::session", "Cannot set the keys of {:?}: {:?}", who, e);
+			}
+			if Balances::hold(
+				&pallet_validator_set::HoldReason::ValidatorBond.into(),
+				&who,
+				ValidatorBond::get(),
+			)
+			.is_err()
+			{
+				log::warn!(target: "runtime::session", "{:?} cannot afford the validator bond", who);
+			}
+			validators.push(who.clone());
+			queued_keys.push((who, keys));
+		}
+
+		let count = validators.len() as u64;
+		pallet_validator_set::Validators::<Runtime>::put(frame_support::BoundedVec::truncate_from(
+			validators.clone(),
+		));
+		pallet_session::Validators::<Runtime>::put(validators);
+		pallet_session::QueuedKeys::<Runtime>::put(queued_keys);
+
+		<Runtime as pallet_session::Config>::WeightInfo::set_keys()
+			.saturating_mul(count)
+			// The bond of each validator, plus the authorities read and the sets written.
+			.saturating_add(db_weight.reads_writes(3 + 2 * count, 3 + 2 * count))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let initialized =
+			bool::decode(&mut &state[..]).map_err(|_| "Failed to decode the pre-upgrade state")?;
+		if !initialized {
+			return Ok(())
+		}
+
+		let authorities: Vec<AccountId> = Aura::authorities()
+			.into_iter()
+			.map(|aura| MultiSigner::from(sp_core::sr25519::Public::from(aura)).into_account())
+			.collect();
+		frame_support::ensure!(
+			pallet_session::Validators::<Runtime>::get() == authorities,
+			"The session validators are not the Aura authorities"
+		);
+		Ok(())
+	}
+}
+
+impl pallet_offences::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type IdentificationTuple = IdentificationTuple<Self>;
+	type OnOffenceHandler = SlashOffenders;
+}
+
+/// Punishes reported offenders by slashing the fraction of their validator bond decided by the
+/// offence. The slashed funds are burned.
+///
+/// Only the bond held by `ValidatorSet` is at stake: the rest of an offender's balance is never
+/// touched, however large the fraction.
+///
+/// Offenders are also disabled as the offence's `DisableStrategy` asks, which makes Aura skip their
+/// slots until the next session.
+pub struct SlashOffenders;
+
+impl OnOffenceHandler<AccountId, IdentificationTuple<Runtime>, Weight> for SlashOffenders {
+	fn on_offence(
+		offenders: &[OffenceDetails<AccountId, IdentificationTuple<Runtime>>],
+		slash_fraction: &[Perbill],
+		_session: SessionIndex,
+		disable_strategy: DisableStrategy,
+	) -> Weight {
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		let bond = RuntimeHoldReason::ValidatorSet(pallet_validator_set::HoldReason::ValidatorBond);
+		let mut weight = Weight::zero();
+		for (details, fraction) in offenders.iter().zip(slash_fraction) {
+			let (offender, _) = &details.offender;
+			let amount = *fraction * Balances::balance_on_hold(&bond, offender);
+			let (_burned, _) = <Balances as BalancedHold<_>>::slash(&bond, offender, amount);
+			weight.saturating_accrue(db_weight.reads_writes(2, 2));
+
+			let disable = match disable_strategy {
+				DisableStrategy::Never => false,
+				DisableStrategy::WhenSlashed => !fraction.is_zero(),
+				DisableStrategy::Always => true,
+			};
+			if disable {
+				Session::disable(offender);
+				weight.saturating_accrue(db_weight.reads_writes(2, 1));
+			}
+		}
+		weight
+	}
+}
 
-	type KeyOwnerProof = sp_core::Void;
-	type EquivocationReportSystem = ();
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = RuntimeCall;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	fn create_transaction<S: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Nonce,
+	) -> Option<(
+		RuntimeCall,
+		<UncheckedExtrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload,
+	)> {
+		// Offchain transactions are mortal, and valid for as long as their block hash is known.
+		let period =
+			BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+		let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+		let extra: SignedExtra = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_asset_tx_payment::ChargeAssetTxPayment::<Runtime>::from(0, None),
+		);
+		let raw_payload = SignedPayload::new(call, extra).ok()?;
+		let signature = raw_payload.using_encoded(|payload| S::sign(payload, public))?;
+		let (call, extra, _) = raw_payload.deconstruct();
+		Some((call, (sp_runtime::MultiAddress::Id(account), signature, extra)))
+	}
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -205,12 +502,9 @@ impl pallet_timestamp::Config for Runtime {
 	type WeightInfo = ();
 }
 
-/// Existential deposit.
-pub const EXISTENTIAL_DEPOSIT: u128 = 500;
-
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = ConstU32<50>;
-	type MaxReserves = ();
+	type MaxReserves = ConstU32<50>;
 	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
@@ -220,23 +514,217 @@ impl pallet_balances::Config for Runtime {
 	type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
 	type AccountStore = System;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
-	type FreezeIdentifier = ();
-	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
-	type RuntimeFreezeReason = ();
+	/// Pallets place named holds and freezes under the reasons aggregated by the runtime.
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<8>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+}
+
+/// The fee charged for the weight of the cheapest extrinsic, [`ExtrinsicBaseWeight`], before the
+/// fee multiplier is applied.
+pub const BASE_EXTRINSIC_FEE: Balance = MILLI_UNIT / 10;
+
+/// Converts weight to fee linearly, so that [`ExtrinsicBaseWeight`] costs [`BASE_EXTRINSIC_FEE`].
+pub struct WeightToFee;
+
+impl WeightToFeePolynomial for WeightToFee {
+	type Balance = Balance;
+
+	fn polynomial() -> WeightToFeeCoefficients<Self::Balance> {
+		let p = BASE_EXTRINSIC_FEE;
+		let q = Balance::from(ExtrinsicBaseWeight::get().ref_time());
+		smallvec::smallvec![WeightToFeeCoefficient {
+			degree: 1,
+			negative: false,
+			coeff_frac: Perbill::from_rational(p % q, q),
+			coeff_integer: p / q,
+		}]
+	}
+}
+
+/// The parameters root can tune through `pallet_parameters` without a runtime upgrade.
+///
+/// The existential deposit, [`BlockHashCount`] and `NORMAL_DISPATCH_RATIO` stay constants: existing
+/// accounts, mortal transactions and the block limits derived at compile time rely on them.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	/// The transaction fees.
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod fees {
+		/// The fee charged for every byte of an extrinsic.
+		#[codec(index = 0)]
+		pub static TransactionByteFee: Balance = MICRO_UNIT;
+
+		/// The block fullness, as a portion of the normal dispatch class, that the fee multiplier
+		/// aims for. Fuller blocks raise fees, emptier blocks lower them.
+		#[codec(index = 1)]
+		pub static TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
+
+		/// How many times more operational extrinsics pay for their priority than normal ones.
+		#[codec(index = 2)]
+		pub static OperationalFeeMultiplier: u8 = 5;
+	}
+
+	/// The limits of `pallet_template`.
+	///
+	/// The pallet still lists them as constants in its metadata. There they show the values of
+	/// the block the metadata was built at, which are the defaults below on a fresh chain, while
+	/// clients cache metadata for as long as the runtime version stays the same. Read the current
+	/// values from the storage of `pallet_parameters` instead.
+	///
+	/// Root may set `MinValue` above `MaxValue`: the pallet is then given `MaxValue` for both, see
+	/// [`TemplateMinValue`].
+	#[dynamic_pallet_params]
+	#[codec(index = 1)]
+	pub mod template {
+		/// The deposit held for every stored value.
+		#[codec(index = 0)]
+		pub static SomethingDeposit: Balance = 10 * EXISTENTIAL_DEPOSIT;
+
+		/// The smallest value that can be stored.
+		#[codec(index = 1)]
+		pub static MinValue: u32 = 1;
+
+		/// The largest value that can be stored.
+		#[codec(index = 2)]
+		pub static MaxValue: u32 = 1_000_000;
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Fees(dynamic_params::fees::Parameters::TransactionByteFee(
+			dynamic_params::fees::TransactionByteFee,
+			Some(MICRO_UNIT),
+		))
+	}
+}
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = AsEnsureOriginWithArg<EnsureRoot<AccountId>>;
+	type WeightInfo = pallet_parameters::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
-	pub FeeMultiplier: Multiplier = Multiplier::one();
+	/// How fast the multiplier reacts to blocks away from `TargetBlockFullness`.
+	pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(75, 1_000_000);
+	/// The multiplier never drops below this value, so that fees can recover from quiet periods.
+	pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 10u128);
+	/// The multiplier never rises above this value.
+	pub MaximumMultiplier: Multiplier = Bounded::max_value();
+}
+
+/// Updates the fee multiplier after each block, slowly raising fees while blocks are fuller than
+/// [`dynamic_params::fees::TargetBlockFullness`] and lowering them while they are emptier.
+pub type SlowAdjustingFeeUpdate<R> = TargetedFeeAdjustment<
+	R,
+	dynamic_params::fees::TargetBlockFullness,
+	AdjustmentVariable,
+	MinimumMultiplier,
+	MaximumMultiplier,
+>;
+
+type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
+
+/// Credits the author of the current block, or burns the funds if there is none.
+pub struct ToAuthor;
+
+impl OnUnbalanced<NegativeImbalance> for ToAuthor {
+	fn on_nonzero_unbalanced(amount: NegativeImbalance) {
+		if let Some(author) = Authorship::author() {
+			Balances::resolve_creating(&author, amount);
+		}
+	}
+}
+
+/// Splits transaction fees between the treasury and the block author.
+///
+/// Of the fees, 80% go to the treasury and 20% to the author. Tips go to the author in full.
+pub struct DealWithFees;
+
+impl OnUnbalanced<NegativeImbalance> for DealWithFees {
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
+		if let Some(fees) = fees_then_tips.next() {
+			let (to_treasury, mut to_author) = fees.ration(80, 20);
+			if let Some(tips) = fees_then_tips.next() {
+				tips.merge_into(&mut to_author);
+			}
+			Treasury::on_unbalanced(to_treasury);
+			ToAuthor::on_unbalanced(to_author);
+		}
+	}
 }
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
-	type OperationalFeeMultiplier = ConstU8<5>;
-	type WeightToFee = IdentityFee<Balance>;
-	type LengthToFee = IdentityFee<Balance>;
-	type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
+	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees>;
+	type OperationalFeeMultiplier = dynamic_params::fees::OperationalFeeMultiplier;
+	type WeightToFee = WeightToFee;
+	type LengthToFee = ConstantMultiplier<Balance, dynamic_params::fees::TransactionByteFee>;
+	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
+}
+
+impl pallet_authorship::Config for Runtime {
+	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
+	type EventHandler = ();
+}
+
+parameter_types! {
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub TreasuryAccount: AccountId = Treasury::account_id();
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	pub const SpendPeriod: BlockNumber = DAYS;
+	pub const Burn: Permill = Permill::from_percent(0);
+	pub const SpendPayoutPeriod: BlockNumber = 30 * DAYS;
+	pub const MaxBalance: Balance = Balance::MAX;
+}
+
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = EnsureRootOrHalfCouncil;
+	type RejectOrigin = EnsureRootOrHalfCouncil;
+	type RuntimeEvent = RuntimeEvent;
+	type OnSlash = Treasury;
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type ProposalBondMaximum = ();
+	type SpendPeriod = SpendPeriod;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type MaxApprovals = ConstU32<100>;
+	type SpendOrigin = EnsureWithSuccess<EnsureRoot<AccountId>, AccountId, MaxBalance>;
+	type AssetKind = ();
+	type Beneficiary = AccountId;
+	type BeneficiaryLookup = IdentityLookup<AccountId>;
+	type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
+	type BalanceConverter = UnityAssetBalanceConversion;
+	type PayoutPeriod = SpendPayoutPeriod;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TreasuryBenchmarkHelper;
+}
+
+/// Creates the arguments of treasury spends for the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TreasuryBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_treasury::ArgumentsFactory<(), AccountId> for TreasuryBenchmarkHelper {
+	fn create_asset_kind(_seed: u32) {}
+
+	fn create_beneficiary(seed: [u8; 32]) -> AccountId {
+		AccountId::from(seed)
+	}
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -245,53 +733,619 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const SudoPalletName: &'static str = "Sudo";
+}
+
+/// Deletes the storage left behind by `Sudo`.
+///
+/// Governance below works alongside `Sudo` so that a chain can hand over to the council and
+/// democracy step by step. Once nothing relies on the sudo key any more, remove `Sudo` from the
+/// runtime and add this migration to [`Migrations`] in the same runtime upgrade.
+pub type RemoveSudo = frame_support::migrations::RemovePallet<
+	SudoPalletName,
+	<Runtime as frame_system::Config>::DbWeight,
+>;
+
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type MaxScheduledPerBlock = ConstU32<50>;
+	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = Preimage;
+}
+
+parameter_types! {
+	pub const PreimageBaseDeposit: Balance = deposit(2, 64);
+	pub const PreimageByteDeposit: Balance = deposit(0, 1);
+	pub const PreimageHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	/// Accounts noting a preimage have a deposit held for as long as it is stored. Preimages
+	/// noted by the `ManagerOrigin`, e.g. for a scheduled runtime upgrade, are free.
+	type Consideration = HoldConsideration<
+		AccountId,
+		Balances,
+		PreimageHoldReason,
+		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+	>;
+}
+
+parameter_types! {
+	pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+	pub const CouncilMaxProposals: u32 = 100;
+	pub const CouncilMaxMembers: u32 = 100;
+	pub MaxProposalWeight: Weight = Perbill::from_percent(50) * BlockWeights::get().max_block;
+}
+
+/// The council, the collective in charge of day-to-day governance.
+pub type CouncilCollective = Instance1;
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = CouncilMotionDuration;
+	type MaxProposals = CouncilMaxProposals;
+	type MaxMembers = CouncilMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+	type SetMembersOrigin = EnsureRoot<AccountId>;
+	type MaxProposalWeight = MaxProposalWeight;
+}
+
+/// Root, or more than half of the council.
+pub type EnsureRootOrHalfCouncil = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+>;
+
+parameter_types! {
+	pub const LaunchPeriod: BlockNumber = 7 * DAYS;
+	pub const VotingPeriod: BlockNumber = 7 * DAYS;
+	pub const FastTrackVotingPeriod: BlockNumber = 3 * HOURS;
+	pub const EnactmentPeriod: BlockNumber = DAYS;
+	pub const CooloffPeriod: BlockNumber = 7 * DAYS;
+	pub const MinimumDeposit: Balance = 1_000 * EXISTENTIAL_DEPOSIT;
+}
+
+impl pallet_democracy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type EnactmentPeriod = EnactmentPeriod;
+	type LaunchPeriod = LaunchPeriod;
+	type VotingPeriod = VotingPeriod;
+	type VoteLockingPeriod = EnactmentPeriod;
+	type MinimumDeposit = MinimumDeposit;
+	/// Half of the council can have the next scheduled referendum be a straight majority-carries
+	/// vote.
+	type ExternalOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
+	/// Three quarters of the council can have the next scheduled referendum be a majority-carries
+	/// vote.
+	type ExternalMajorityOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>;
+	/// The whole council can have the next scheduled referendum be a negative-turnout-bias vote.
+	type ExternalDefaultOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+	type SubmitOrigin = EnsureSigned<AccountId>;
+	/// Two thirds of the council can have an external proposal voted on sooner.
+	type FastTrackOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+	/// The whole council can have an external proposal voted on and enacted immediately.
+	type InstantOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+	type InstantAllowed = ConstBool<true>;
+	type FastTrackVotingPeriod = FastTrackVotingPeriod;
+	/// Two thirds of the council can cancel a passed referendum.
+	type CancellationOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+	type BlacklistOrigin = EnsureRoot<AccountId>;
+	/// The whole council can cancel a public proposal.
+	type CancelProposalOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>,
+	>;
+	/// Any council member can veto an external proposal once per cool-off period.
+	type VetoOrigin = pallet_collective::EnsureMember<AccountId, CouncilCollective>;
+	type CooloffPeriod = CooloffPeriod;
+	type Slash = Treasury;
+	type Scheduler = Scheduler;
+	type PalletsOrigin = OriginCaller;
+	type MaxVotes = ConstU32<100>;
+	type WeightInfo = pallet_democracy::weights::SubstrateWeight<Runtime>;
+	type MaxProposals = ConstU32<100>;
+	type Preimages = Preimage;
+	type MaxDeposits = ConstU32<100>;
+	type MaxBlacklisted = ConstU32<100>;
+}
+
+/// The deposit for keeping `items` storage items of `bytes` bytes in total.
+pub const fn deposit(items: u32, bytes: u32) -> Balance {
+	items as Balance * 100 * EXISTENTIAL_DEPOSIT + bytes as Balance * EXISTENTIAL_DEPOSIT
+}
+
+impl pallet_utility::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	// One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
+	pub const DepositBase: Balance = deposit(1, 88);
+	// Additional storage item size of 32 bytes.
+	pub const DepositFactor: Balance = deposit(0, 32);
+}
+
+impl pallet_multisig::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type DepositBase = DepositBase;
+	type DepositFactor = DepositFactor;
+	type MaxSignatories = ConstU32<100>;
+	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	// One storage item; key size 32, value size 8.
+	pub const ProxyDepositBase: Balance = deposit(1, 8);
+	// Additional storage item size of 33 bytes.
+	pub const ProxyDepositFactor: Balance = deposit(0, 33);
+	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
+	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+}
+
+/// The kinds of calls a proxy may make on behalf of the account that registered it.
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Default,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+)]
+pub enum ProxyType {
+	/// Any call.
+	#[default]
+	Any,
+	/// Any call that cannot move funds out of the account.
+	NonTransfer,
+	/// Council, democracy and treasury calls.
+	Governance,
+	/// Calls to the template pallet.
+	Template,
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, c: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::NonTransfer => {
+				// `evm.call` and `evm.withdraw` move balance too.
+				#[cfg(feature = "evm")]
+				if matches!(c, RuntimeCall::EVM(..)) {
+					return false
+				}
+				!matches!(
+					c,
+					RuntimeCall::Balances(..) |
+						RuntimeCall::Assets(..) |
+						RuntimeCall::Vesting(pallet_vesting::Call::vested_transfer { .. }) |
+						RuntimeCall::Contracts(..)
+				)
+			},
+			ProxyType::Governance => matches!(
+				c,
+				RuntimeCall::Council(..) |
+					RuntimeCall::Democracy(..) |
+					RuntimeCall::Treasury(..) |
+					RuntimeCall::Utility(..)
+			),
+			ProxyType::Template =>
+				matches!(c, RuntimeCall::TemplateModule(..) | RuntimeCall::Utility(..)),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			(_, ProxyType::Any) => false,
+			(ProxyType::NonTransfer, _) => true,
+			_ => false,
+		}
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = ConstU32<32>;
+	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+	type MaxPending = ConstU32<32>;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+}
+
+parameter_types! {
+	pub const MinVestedTransfer: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = MinVestedTransfer;
+	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+parameter_types! {
+	pub const DepositPerItem: Balance = deposit(1, 0);
+	pub const DepositPerByte: Balance = deposit(0, 1);
+	pub const DefaultDepositLimit: Balance = deposit(1024, 1024 * 1024);
+	pub Schedule: pallet_contracts::Schedule<Runtime> = Default::default();
+	pub CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
+}
+
+impl pallet_contracts::Config for Runtime {
+	type Time = Timestamp;
+	/// Good enough for tests and demos, but predictable by block authors: contracts on a live
+	/// chain should not rely on it for anything of value.
+	type Randomness = RandomnessCollectiveFlip;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	/// Contracts cannot dispatch runtime calls.
+	type CallFilter = frame_support::traits::Nothing;
+	type DepositPerItem = DepositPerItem;
+	type DepositPerByte = DepositPerByte;
+	type DefaultDepositLimit = DefaultDepositLimit;
+	type CallStack = [pallet_contracts::Frame<Self>; 5];
+	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
+	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
+	type ChainExtension = chain_extension::TemplateExtension;
+	type Schedule = Schedule;
+	type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
+	type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
+	type MaxStorageKeyLen = ConstU32<128>;
+	type UnsafeUnstableInterface = ConstBool<false>;
+	type UploadOrigin = EnsureSigned<Self::AccountId>;
+	type InstantiateOrigin = EnsureSigned<Self::AccountId>;
+	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_contracts::migration::codegen::BenchMigrations;
+	type MaxDelegateDependencies = ConstU32<32>;
+	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+	type Debug = ();
+	type Environment = ();
+	type Xcm = ();
+}
+
+parameter_types! {
+	// Creating an asset is expensive, to keep its ids from being squatted.
+	pub const AssetDeposit: Balance = 100 * UNIT;
+	pub const AssetAccountDeposit: Balance = deposit(1, 16);
+	pub const MetadataDepositBase: Balance = deposit(1, 68);
+	pub const MetadataDepositPerByte: Balance = deposit(0, 1);
+	pub const ApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
+}
+
+/// The instance of `pallet_assets` holding the fungible assets anyone can create, next to the
+/// native currency of `Balances`.
+pub type AssetsInstance = Instance1;
+
+impl pallet_assets::Config<AssetsInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+/// Splits transaction fees paid in an asset, without their tips, the way [`DealWithFees`] splits
+/// those paid in the native currency: 80% go to the treasury account and 20% to the block author.
+///
+/// A share that cannot be deposited is burnt, e.g. if there is no author, or if it is below the
+/// minimum balance of the asset and its recipient holds none of the asset yet.
+pub struct DealWithAssetFees;
+
+impl HandleCredit<AccountId, Assets> for DealWithAssetFees {
+	fn handle_credit(credit: fungibles::Credit<AccountId, Assets>) {
+		let to_treasury = credit.peek().saturating_mul(80) / 100;
+		let (to_treasury, to_author) = credit.split(to_treasury);
+		// On failure, a credit is dropped and thereby burnt.
+		let _ = Assets::resolve(&TreasuryAccount::get(), to_treasury);
+		if let Some(author) = Authorship::author() {
+			let _ = Assets::resolve(&author, to_author);
+		}
+	}
+}
+
+/// Converts fees to an asset at the ratio of the minimum balance of the asset to the existential
+/// deposit, which only sufficient assets can be used for.
+type AssetFeeConversion =
+	pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto, AssetsInstance>;
+
+/// Charges transaction fees in an asset like [`FungiblesAdapter`], except that the tip goes to
+/// the block author in full, as it does for fees paid in the native currency. The rest of the fee
+/// is split by [`DealWithAssetFees`].
+pub struct ChargeAssetFees;
+
+impl OnChargeAssetTransaction<Runtime> for ChargeAssetFees {
+	type Balance = Balance;
+	type AssetId = u32;
+	type LiquidityInfo = fungibles::Credit<AccountId, Assets>;
+
+	fn withdraw_fee(
+		who: &AccountId,
+		call: &RuntimeCall,
+		dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		asset_id: Self::AssetId,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		FungiblesAdapter::<AssetFeeConversion, DealWithAssetFees>::withdraw_fee(
+			who,
+			call,
+			dispatch_info,
+			asset_id,
+			fee,
+			tip,
+		)
+	}
+
+	fn correct_and_deposit_fee(
+		who: &AccountId,
+		_dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		_post_info: &PostDispatchInfoOf<RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		paid: Self::LiquidityInfo,
+	) -> Result<(Balance, Balance), TransactionValidityError> {
+		let to_asset_balance = |amount| {
+			AssetFeeConversion::to_asset_balance(amount, paid.asset())
+				.map_err(|_| TransactionValidityError::from(InvalidTransaction::Payment))
+		};
+		// As in `FungiblesAdapter`, a non-zero fee costs at least one unit of the asset, however
+		// the conversion rounds. The corrected fee includes the tip.
+		let min_fee = if corrected_fee.is_zero() { 0 } else { 1 };
+		let fee = to_asset_balance(corrected_fee)?.max(min_fee);
+		let tip = to_asset_balance(tip)?.min(fee);
+
+		let (paid_fee, refund) = paid.split(fee);
+		// If the refund cannot be deposited, the payer gets nothing back.
+		let _ = Assets::resolve(who, refund);
+		let (paid_tip, paid_fee) = paid_fee.split(tip);
+		DealWithAssetFees::handle_credit(paid_fee);
+		// On failure, e.g. if there is no author, the tip is dropped and thereby burnt.
+		if let Some(author) = Authorship::author() {
+			let _ = Assets::resolve(&author, paid_tip);
+		}
+		Ok((fee, tip))
+	}
+}
+
+impl pallet_asset_tx_payment::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Fungibles = Assets;
+	type OnChargeAssetTransaction = ChargeAssetFees;
+}
+
+/// The accounts of the validators of the current session.
+///
+/// The offchain worker of the template pallet signs its unsigned transactions with a key that
+/// must belong to one of them, so that the fee-free transactions cannot be spammed by anyone.
+pub struct SessionValidators;
+
+impl Contains<AccountId> for SessionValidators {
+	fn contains(who: &AccountId) -> bool {
+		pallet_session::Validators::<Runtime>::get().contains(who)
+	}
+}
+
+/// The smallest value `pallet_template` accepts: [`dynamic_params::template::MinValue`], but never
+/// more than [`dynamic_params::template::MaxValue`].
+///
+/// `pallet_parameters` sets each parameter on its own and shows its admin origin only the key, so
+/// nothing stops root from setting the bounds the wrong way around. Capping the minimum keeps the
+/// range of accepted values from ever being empty.
+pub struct TemplateMinValue;
+
+impl Get<u32> for TemplateMinValue {
+	fn get() -> u32 {
+		dynamic_params::template::MinValue::get().min(dynamic_params::template::MaxValue::get())
+	}
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type SomethingDeposit = dynamic_params::template::SomethingDeposit;
+	type MinValue = TemplateMinValue;
+	type MaxValue = dynamic_params::template::MaxValue;
+	type AuthorityId = pallet_template::crypto::AuthorityId;
+	type OffchainAuthorities = SessionValidators;
+	// Low enough not to crowd out the transactions that pay for their inclusion.
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
 }
 
-// Create the runtime by composing the FRAME pallets that were previously configured.
-#[frame_support::runtime]
-mod runtime {
-	#[runtime::runtime]
-	#[runtime::derive(
-		RuntimeCall,
-		RuntimeEvent,
-		RuntimeError,
-		RuntimeOrigin,
-		RuntimeFreezeReason,
-		RuntimeHoldReason,
-		RuntimeSlashReason,
-		RuntimeLockId,
-		RuntimeTask
-	)]
-	pub struct Runtime;
+/// Declares the runtime, with the pallets of enabled optional features passed as `$extra`.
+///
+/// `#[frame_support::runtime]` does not support `#[cfg]` on pallets, so every feature adding
+/// pallets expands this macro with its own.
+macro_rules! construct_runtime_with {
+	($($extra:tt)*) => {
+		// Create the runtime by composing the FRAME pallets that were previously configured.
+		#[frame_support::runtime]
+		mod runtime {
+			#[runtime::runtime]
+			#[runtime::derive(
+				RuntimeCall,
+				RuntimeEvent,
+				RuntimeError,
+				RuntimeOrigin,
+				RuntimeFreezeReason,
+				RuntimeHoldReason,
+				RuntimeSlashReason,
+				RuntimeLockId,
+				RuntimeTask
+			)]
+			pub struct Runtime;
+
+			#[runtime::pallet_index(0)]
+			pub type System = frame_system;
+
+			#[runtime::pallet_index(1)]
+			pub type Timestamp = pallet_timestamp;
+
+			#[runtime::pallet_index(2)]
+			pub type Aura = pallet_aura;
+
+			#[runtime::pallet_index(3)]
+			pub type Grandpa = pallet_grandpa;
+
+			#[runtime::pallet_index(4)]
+			pub type Balances = pallet_balances;
+
+			#[runtime::pallet_index(5)]
+			pub type TransactionPayment = pallet_transaction_payment;
+
+			#[runtime::pallet_index(6)]
+			pub type Sudo = pallet_sudo;
+
+			// Include the custom logic from the pallet-template in the runtime.
+			#[runtime::pallet_index(7)]
+			pub type TemplateModule = pallet_template;
+
+			// Must come before `Session`, which reads the genesis validators from it.
+			#[runtime::pallet_index(11)]
+			pub type ValidatorSet = pallet_validator_set;
+
+			#[runtime::pallet_index(8)]
+			pub type Session = pallet_session;
+
+			#[runtime::pallet_index(9)]
+			pub type Historical = pallet_session_historical;
+
+			#[runtime::pallet_index(10)]
+			pub type Offences = pallet_offences;
+
+			#[runtime::pallet_index(12)]
+			pub type Authorship = pallet_authorship;
+
+			#[runtime::pallet_index(13)]
+			pub type Treasury = pallet_treasury;
+
+			#[runtime::pallet_index(14)]
+			pub type Scheduler = pallet_scheduler;
 
-	#[runtime::pallet_index(0)]
-	pub type System = frame_system;
+			#[runtime::pallet_index(15)]
+			pub type Preimage = pallet_preimage;
 
-	#[runtime::pallet_index(1)]
-	pub type Timestamp = pallet_timestamp;
+			#[runtime::pallet_index(16)]
+			pub type Council = pallet_collective<Instance1>;
 
-	#[runtime::pallet_index(2)]
-	pub type Aura = pallet_aura;
+			#[runtime::pallet_index(17)]
+			pub type Democracy = pallet_democracy;
 
-	#[runtime::pallet_index(3)]
-	pub type Grandpa = pallet_grandpa;
+			#[runtime::pallet_index(18)]
+			pub type Utility = pallet_utility;
 
-	#[runtime::pallet_index(4)]
-	pub type Balances = pallet_balances;
+			#[runtime::pallet_index(19)]
+			pub type Multisig = pallet_multisig;
 
-	#[runtime::pallet_index(5)]
-	pub type TransactionPayment = pallet_transaction_payment;
+			#[runtime::pallet_index(20)]
+			pub type Proxy = pallet_proxy;
 
-	#[runtime::pallet_index(6)]
-	pub type Sudo = pallet_sudo;
+			#[runtime::pallet_index(21)]
+			pub type Vesting = pallet_vesting;
 
-	// Include the custom logic from the pallet-template in the runtime.
-	#[runtime::pallet_index(7)]
-	pub type TemplateModule = pallet_template;
+			#[runtime::pallet_index(22)]
+			pub type Parameters = pallet_parameters;
+
+			#[runtime::pallet_index(23)]
+			pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
+
+			#[runtime::pallet_index(24)]
+			pub type Contracts = pallet_contracts;
+
+			#[runtime::pallet_index(27)]
+			pub type Assets = pallet_assets<Instance1>;
+
+			#[runtime::pallet_index(28)]
+			pub type AssetTxPayment = pallet_asset_tx_payment;
+
+			$($extra)*
+		}
+	};
+}
+
+#[cfg(not(feature = "evm"))]
+construct_runtime_with!();
+
+#[cfg(feature = "evm")]
+construct_runtime_with! {
+	#[runtime::pallet_index(25)]
+	pub type Ethereum = pallet_ethereum;
+
+	#[runtime::pallet_index(26)]
+	pub type EVM = pallet_evm;
 }
 
 /// The address format for describing accounts.
@@ -309,18 +1363,30 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_asset_tx_payment::ChargeAssetTxPayment<Runtime>,
 );
 
 /// All migrations of the runtime, aside from the ones declared in the pallets.
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	InitializeSession,
+	// The treasury keeps the global value of `pallet_template`'s first storage version, which
+	// had no owner; root can remove it like any other value.
+	pallet_template::migrations::v1::MigrateV0ToV1<Runtime, TreasuryAccount>,
+	pallet_template::migrations::v2::MigrateV1ToV2<Runtime, Balances>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
+#[cfg(not(feature = "evm"))]
 pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
+/// Unchecked extrinsic type as expected by this runtime, accepting self-contained Ethereum
+/// transactions next to signed and unsigned extrinsics.
+#[cfg(feature = "evm")]
+pub type UncheckedExtrinsic =
+	fp_self_contained::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
@@ -333,15 +1399,31 @@ pub type Executive = frame_executive::Executive<
 	Migrations,
 >;
 
+#[cfg(test)]
+mod tests;
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benches {
 	frame_benchmarking::define_benchmarks!(
 		[frame_benchmarking, BaselineBench::<Runtime>]
 		[frame_system, SystemBench::<Runtime>]
+		[pallet_assets, Assets]
 		[pallet_balances, Balances]
+		[pallet_collective, Council]
+		[pallet_democracy, Democracy]
+		[pallet_multisig, Multisig]
+		[pallet_preimage, Preimage]
+		[pallet_proxy, Proxy]
+		[pallet_scheduler, Scheduler]
 		[pallet_timestamp, Timestamp]
+		[pallet_treasury, Treasury]
+		[pallet_utility, Utility]
+		[pallet_vesting, Vesting]
+		[pallet_parameters, Parameters]
+		[pallet_contracts, Contracts]
 		[pallet_sudo, Sudo]
 		[pallet_template, TemplateModule]
+		[pallet_validator_set, ValidatorSet]
 	);
 }
 
@@ -443,23 +1525,27 @@ impl_runtime_apis! {
 		}
 
 		fn submit_report_equivocation_unsigned_extrinsic(
-			_equivocation_proof: sp_consensus_grandpa::EquivocationProof<
+			equivocation_proof: sp_consensus_grandpa::EquivocationProof<
 				<Block as BlockT>::Hash,
 				NumberFor<Block>,
 			>,
-			_key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
+			key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
 		) -> Option<()> {
-			None
+			let key_owner_proof = key_owner_proof.decode()?;
+
+			Grandpa::submit_unsigned_equivocation_report(
+				equivocation_proof,
+				key_owner_proof,
+			)
 		}
 
 		fn generate_key_ownership_proof(
 			_set_id: sp_consensus_grandpa::SetId,
-			_authority_id: GrandpaId,
+			authority_id: GrandpaId,
 		) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
-			// NOTE: this is the only implementation possible since we've
-			// defined our key owner proof type as a bottom type (i.e. a type
-			// with no values).
-			None
+			Historical::prove((sp_consensus_grandpa::KEY_TYPE, authority_id))
+				.map(|p| p.encode())
+				.map(sp_consensus_grandpa::OpaqueKeyOwnershipProof::new)
 		}
 	}
 
@@ -490,6 +1576,95 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl scheduler_api::SchedulerApi<Block, BlockNumber, pallet_scheduler::ScheduledOf<Runtime>>
+		for Runtime
+	{
+		fn agenda(when: BlockNumber) -> Vec<(u32, pallet_scheduler::ScheduledOf<Runtime>)> {
+			pallet_scheduler::Agenda::<Runtime>::get(when)
+				.into_iter()
+				.enumerate()
+				.filter_map(|(index, task)| task.map(|task| (index as u32, task)))
+				.collect()
+		}
+
+		fn scheduled_blocks() -> Vec<(BlockNumber, u32)> {
+			let mut blocks: Vec<_> = pallet_scheduler::Agenda::<Runtime>::iter()
+				.map(|(when, agenda)| (when, agenda.iter().flatten().count() as u32))
+				.filter(|(_, count)| *count > 0)
+				.collect();
+			blocks.sort_unstable_by_key(|(when, _)| *when);
+			blocks
+		}
+	}
+
+	impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, EventRecord>
+		for Runtime
+	{
+		fn call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> pallet_contracts::ContractExecResult<Balance, EventRecord> {
+			let gas_limit = gas_limit.unwrap_or(BlockWeights::get().max_block);
+			Contracts::bare_call(
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data,
+				pallet_contracts::DebugInfo::UnsafeDebug,
+				pallet_contracts::CollectEvents::UnsafeCollect,
+				pallet_contracts::Determinism::Enforced,
+			)
+		}
+
+		fn instantiate(
+			origin: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			code: pallet_contracts::Code<Hash>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> pallet_contracts::ContractInstantiateResult<AccountId, Balance, EventRecord> {
+			let gas_limit = gas_limit.unwrap_or(BlockWeights::get().max_block);
+			Contracts::bare_instantiate(
+				origin,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				code,
+				data,
+				salt,
+				pallet_contracts::DebugInfo::UnsafeDebug,
+				pallet_contracts::CollectEvents::UnsafeCollect,
+			)
+		}
+
+		fn upload_code(
+			origin: AccountId,
+			code: Vec<u8>,
+			storage_deposit_limit: Option<Balance>,
+			determinism: pallet_contracts::Determinism,
+		) -> pallet_contracts::CodeUploadResult<Hash, Balance> {
+			Contracts::bare_upload_code(origin, code, storage_deposit_limit, determinism)
+		}
+
+		fn get_storage(address: AccountId, key: Vec<u8>) -> pallet_contracts::GetStorageResult {
+			Contracts::get_storage(address, key)
+		}
+	}
+
+	impl pallet_template_runtime_api::TemplateApi<Block, AccountId> for Runtime {
+		fn get_something(who: AccountId) -> Option<u32> {
+			pallet_template::Something::<Runtime>::get(who).map(|info| info.value)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
 		for Runtime
 	{
@@ -513,6 +1688,214 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[cfg(feature = "evm")]
+	impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
+		fn chain_id() -> u64 {
+			evm::ChainId::get()
+		}
+
+		fn account_basic(address: sp_core::H160) -> pallet_evm::Account {
+			let (account, _) = EVM::account_basic(&address);
+			account
+		}
+
+		fn gas_price() -> sp_core::U256 {
+			let (gas_price, _) = <evm::WeightFeeGasPrice as pallet_evm::FeeCalculator>::min_gas_price();
+			gas_price
+		}
+
+		fn account_code_at(address: sp_core::H160) -> Vec<u8> {
+			pallet_evm::AccountCodes::<Runtime>::get(address)
+		}
+
+		fn author() -> sp_core::H160 {
+			EVM::find_author()
+		}
+
+		fn storage_at(address: sp_core::H160, index: sp_core::U256) -> sp_core::H256 {
+			let mut key = [0u8; 32];
+			index.to_big_endian(&mut key);
+			pallet_evm::AccountStorages::<Runtime>::get(address, sp_core::H256::from(key))
+		}
+
+		fn call(
+			from: sp_core::H160,
+			to: sp_core::H160,
+			data: Vec<u8>,
+			value: sp_core::U256,
+			gas_limit: sp_core::U256,
+			max_fee_per_gas: Option<sp_core::U256>,
+			max_priority_fee_per_gas: Option<sp_core::U256>,
+			nonce: Option<sp_core::U256>,
+			estimate: bool,
+			access_list: Option<Vec<(sp_core::H160, Vec<sp_core::H256>)>>,
+		) -> Result<pallet_evm::CallInfo, sp_runtime::DispatchError> {
+			use pallet_evm::Runner as _;
+
+			let mut config = <Runtime as pallet_evm::Config>::config().clone();
+			config.estimate = estimate;
+
+			let gas_limit = gas_limit.min(u64::MAX.into());
+			let transaction_data = pallet_ethereum::TransactionData::new(
+				pallet_ethereum::TransactionAction::Call(to),
+				data.clone(),
+				nonce.unwrap_or_default(),
+				gas_limit,
+				None,
+				max_fee_per_gas,
+				max_priority_fee_per_gas,
+				value,
+				Some(evm::ChainId::get()),
+				access_list.clone().unwrap_or_default(),
+			);
+			let (weight_limit, proof_size_base_cost) =
+				Ethereum::transaction_weight(&transaction_data);
+
+			<Runtime as pallet_evm::Config>::Runner::call(
+				from,
+				to,
+				data,
+				value,
+				gas_limit.low_u64(),
+				max_fee_per_gas,
+				max_priority_fee_per_gas,
+				nonce,
+				access_list.unwrap_or_default(),
+				false,
+				true,
+				weight_limit,
+				proof_size_base_cost,
+				&config,
+			)
+			.map_err(|err| err.error.into())
+		}
+
+		fn create(
+			from: sp_core::H160,
+			data: Vec<u8>,
+			value: sp_core::U256,
+			gas_limit: sp_core::U256,
+			max_fee_per_gas: Option<sp_core::U256>,
+			max_priority_fee_per_gas: Option<sp_core::U256>,
+			nonce: Option<sp_core::U256>,
+			estimate: bool,
+			access_list: Option<Vec<(sp_core::H160, Vec<sp_core::H256>)>>,
+		) -> Result<pallet_evm::CreateInfo, sp_runtime::DispatchError> {
+			use pallet_evm::Runner as _;
+
+			let mut config = <Runtime as pallet_evm::Config>::config().clone();
+			config.estimate = estimate;
+
+			let gas_limit = gas_limit.min(u64::MAX.into());
+			let transaction_data = pallet_ethereum::TransactionData::new(
+				pallet_ethereum::TransactionAction::Create,
+				data.clone(),
+				nonce.unwrap_or_default(),
+				gas_limit,
+				None,
+				max_fee_per_gas,
+				max_priority_fee_per_gas,
+				value,
+				Some(evm::ChainId::get()),
+				access_list.clone().unwrap_or_default(),
+			);
+			let (weight_limit, proof_size_base_cost) =
+				Ethereum::transaction_weight(&transaction_data);
+
+			<Runtime as pallet_evm::Config>::Runner::create(
+				from,
+				data,
+				value,
+				gas_limit.low_u64(),
+				max_fee_per_gas,
+				max_priority_fee_per_gas,
+				nonce,
+				access_list.unwrap_or_default(),
+				false,
+				true,
+				weight_limit,
+				proof_size_base_cost,
+				&config,
+			)
+			.map_err(|err| err.error.into())
+		}
+
+		fn current_transaction_statuses() -> Option<Vec<fp_rpc::TransactionStatus>> {
+			pallet_ethereum::CurrentTransactionStatuses::<Runtime>::get()
+		}
+
+		fn current_block() -> Option<pallet_ethereum::Block> {
+			pallet_ethereum::CurrentBlock::<Runtime>::get()
+		}
+
+		fn current_receipts() -> Option<Vec<pallet_ethereum::Receipt>> {
+			pallet_ethereum::CurrentReceipts::<Runtime>::get()
+		}
+
+		fn current_all() -> (
+			Option<pallet_ethereum::Block>,
+			Option<Vec<pallet_ethereum::Receipt>>,
+			Option<Vec<fp_rpc::TransactionStatus>>,
+		) {
+			(
+				pallet_ethereum::CurrentBlock::<Runtime>::get(),
+				pallet_ethereum::CurrentReceipts::<Runtime>::get(),
+				pallet_ethereum::CurrentTransactionStatuses::<Runtime>::get(),
+			)
+		}
+
+		fn extrinsic_filter(
+			xts: Vec<<Block as BlockT>::Extrinsic>,
+		) -> Vec<pallet_ethereum::Transaction> {
+			xts.into_iter()
+				.filter_map(|xt| match xt.0.function {
+					RuntimeCall::Ethereum(pallet_ethereum::Call::transact { transaction }) =>
+						Some(transaction),
+					_ => None,
+				})
+				.collect()
+		}
+
+		fn elasticity() -> Option<Permill> {
+			// The gas price is fixed, there is no base fee to adjust.
+			None
+		}
+
+		fn gas_limit_multiplier_support() {}
+
+		fn pending_block(
+			xts: Vec<<Block as BlockT>::Extrinsic>,
+		) -> (Option<pallet_ethereum::Block>, Option<Vec<fp_rpc::TransactionStatus>>) {
+			use frame_support::traits::OnFinalize;
+
+			for ext in xts.into_iter() {
+				let _ = Executive::apply_extrinsic(ext);
+			}
+
+			Ethereum::on_finalize(System::block_number() + 1);
+
+			(
+				pallet_ethereum::CurrentBlock::<Runtime>::get(),
+				pallet_ethereum::CurrentTransactionStatuses::<Runtime>::get(),
+			)
+		}
+
+		fn initialize_pending_block(header: &<Block as BlockT>::Header) {
+			Executive::initialize_block(header);
+		}
+	}
+
+	#[cfg(feature = "evm")]
+	impl fp_rpc::ConvertTransactionRuntimeApi<Block> for Runtime {
+		fn convert_transaction(
+			transaction: pallet_ethereum::Transaction,
+		) -> <Block as BlockT>::Extrinsic {
+			UncheckedExtrinsic::new_unsigned(
+				pallet_ethereum::Call::<Runtime>::transact { transaction }.into(),
+			)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -585,4 +1968,17 @@ impl_runtime_apis! {
 			build_config::<RuntimeGenesisConfig>(config)
 		}
 	}
+
+	impl genesis_presets_api::GenesisPresetsApi<Block> for Runtime {
+		fn get_preset(id: Vec<u8>) -> Option<Vec<u8>> {
+			genesis_config_presets::get_preset(sp_std::str::from_utf8(&id).ok()?)
+		}
+
+		fn preset_names() -> Vec<Vec<u8>> {
+			genesis_config_presets::preset_names()
+				.into_iter()
+				.map(|id| id.as_bytes().to_vec())
+				.collect()
+		}
+	}
 }
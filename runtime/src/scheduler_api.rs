@@ -0,0 +1,22 @@
+//! Runtime API to inspect the agenda of `pallet_scheduler`.
+//!
+//! The scheduled tasks are returned as stored by the scheduler, i.e. as
+//! [`pallet_scheduler::ScheduledOf`], which clients can decode through the type information in the
+//! metadata.
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// The API to read the tasks scheduled by `pallet_scheduler`.
+	pub trait SchedulerApi<BlockNumber, Scheduled> where
+		BlockNumber: Codec,
+		Scheduled: Codec,
+	{
+		/// The tasks due in block `when`, each with its index in the agenda of that block.
+		fn agenda(when: BlockNumber) -> Vec<(u32, Scheduled)>;
+
+		/// The blocks with tasks due, in ascending order, each with the number of tasks due.
+		fn scheduled_blocks() -> Vec<(BlockNumber, u32)>;
+	}
+}
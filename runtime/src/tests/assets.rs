@@ -0,0 +1,114 @@
+use super::*;
+use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+use pallet_asset_tx_payment::ChargeAssetTxPayment;
+use sp_runtime::{
+	traits::SignedExtension,
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+const ASSET: u32 = 1;
+
+/// The minimum balance of the test asset, twice the existential deposit, so that fees paid in it
+/// are twice the fees paid in the native currency.
+const MIN_BALANCE: Balance = 2 * EXISTENTIAL_DEPOSIT;
+
+/// Create the test asset, owned by the first authority, and mint `amount` of it to each of
+/// `holders`.
+fn create_asset(is_sufficient: bool, holders: &[&AccountId], amount: Balance) {
+	let owner = AUTHORITIES[0].0.to_account_id();
+	assert_ok!(Assets::force_create(
+		RuntimeOrigin::root(),
+		ASSET.into(),
+		owner.clone().into(),
+		is_sufficient,
+		MIN_BALANCE
+	));
+	for who in holders {
+		assert_ok!(Assets::mint(
+			RuntimeOrigin::signed(owner.clone()),
+			ASSET.into(),
+			(*who).clone().into(),
+			amount
+		));
+	}
+}
+
+fn remark() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![0; 32] })
+}
+
+#[test]
+fn fees_can_be_paid_in_a_sufficient_asset() {
+	new_test_ext().execute_with(|| {
+		initialize_block_in_slot(slot_of(0, 0));
+		let author = AUTHORITIES[0].0.to_account_id();
+		// Dave has no native balance: the asset alone keeps his account alive.
+		let dave = Sr25519Keyring::Dave.to_account_id();
+		// The treasury holds the asset already, so that a share below the minimum balance can be
+		// deposited.
+		let treasury = TreasuryAccount::get();
+		create_asset(true, &[&dave, &author, &treasury], UNIT);
+
+		let call = remark();
+		let info = call.get_dispatch_info();
+		let len = call.encoded_size();
+		let fee = TransactionPayment::compute_fee(len as u32, &info, 0);
+
+		let pre = ChargeAssetTxPayment::<Runtime>::from(0, Some(ASSET))
+			.pre_dispatch(&dave, &call, &info, len)
+			.unwrap();
+		assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+			Some(pre),
+			&info,
+			&().into(),
+			len,
+			&Ok(())
+		));
+
+		assert_eq!(Assets::balance(ASSET, &dave), UNIT - 2 * fee);
+		// The fee is split like fees paid in the native currency.
+		let to_treasury = 2 * fee * 80 / 100;
+		assert_eq!(Assets::balance(ASSET, &treasury), UNIT + to_treasury);
+		assert_eq!(Assets::balance(ASSET, &author), UNIT + 2 * fee - to_treasury);
+		assert_eq!(Balances::free_balance(&dave), 0);
+	});
+}
+
+#[test]
+fn fees_cannot_be_paid_in_an_insufficient_asset() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+		create_asset(false, &[&alice], UNIT);
+
+		let call = remark();
+		let info = call.get_dispatch_info();
+		let len = call.encoded_size();
+
+		assert_eq!(
+			ChargeAssetTxPayment::<Runtime>::from(0, Some(ASSET))
+				.pre_dispatch(&alice, &call, &info, len)
+				.unwrap_err(),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
+		assert_eq!(Assets::balance(ASSET, &alice), UNIT);
+	});
+}
+
+#[test]
+fn fees_default_to_the_native_currency() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+		create_asset(true, &[&alice], UNIT);
+
+		let call = remark();
+		let info = call.get_dispatch_info();
+		let len = call.encoded_size();
+		let fee = TransactionPayment::compute_fee(len as u32, &info, 0);
+
+		assert_ok!(
+			ChargeAssetTxPayment::<Runtime>::from(0, None).pre_dispatch(&alice, &call, &info, len)
+		);
+		assert_eq!(Balances::free_balance(&alice), INITIAL_BALANCE - fee);
+		assert_eq!(Assets::balance(ASSET, &alice), UNIT);
+	});
+}
@@ -0,0 +1,69 @@
+use super::{
+	grandpa::{generate_equivocation_proof, key_owner_proof},
+	*,
+};
+use frame_support::assert_ok;
+
+#[test]
+fn equivocating_authority_is_disabled() {
+	new_test_ext().execute_with(|| {
+		let (offender, keyring) = AUTHORITIES[1];
+		let equivocation_proof = generate_equivocation_proof(Grandpa::current_set_id(), 1, keyring);
+		assert_ok!(Grandpa::report_equivocation_unsigned(
+			RuntimeOrigin::none(),
+			Box::new(equivocation_proof),
+			key_owner_proof(keyring),
+		));
+
+		let index = Session::validators()
+			.iter()
+			.position(|v| v == &offender.to_account_id())
+			.unwrap() as u32;
+		assert_eq!(Session::disabled_validators(), vec![index]);
+		assert!(<Session as frame_support::traits::DisabledValidators>::is_disabled(index));
+	});
+}
+
+#[test]
+fn offence_without_disabling_keeps_the_author_enabled() {
+	new_test_ext().execute_with(|| {
+		let offender = AUTHORITIES[0].0.to_account_id();
+		let details = OffenceDetails { offender: (offender.clone(), offender), reporters: vec![] };
+		SlashOffenders::on_offence(
+			&[details],
+			&[Perbill::from_percent(10)],
+			Session::current_index(),
+			DisableStrategy::Never,
+		);
+		assert!(Session::disabled_validators().is_empty());
+	});
+}
+
+#[test]
+fn enabled_authorities_author_in_their_slots() {
+	new_test_ext().execute_with(|| {
+		assert!(Session::disable(&AUTHORITIES[1].0.to_account_id()));
+		initialize_block_in_slot(slot_of(0, 0));
+		initialize_block_in_slot(slot_of(2, 3));
+	});
+}
+
+#[test]
+#[should_panic(expected = "is disabled")]
+fn disabled_authority_slot_is_skipped() {
+	new_test_ext().execute_with(|| {
+		assert!(Session::disable(&AUTHORITIES[1].0.to_account_id()));
+		// A block claiming the disabled author's slot is rejected by the runtime.
+		initialize_block_in_slot(slot_of(1, 0));
+	});
+}
+
+#[test]
+fn disabled_authority_authors_again_in_the_next_session() {
+	new_test_ext().execute_with(|| {
+		assert!(Session::disable(&AUTHORITIES[1].0.to_account_id()));
+		start_session(1);
+		assert!(Session::disabled_validators().is_empty());
+		initialize_block_in_slot(slot_of(1, 0));
+	});
+}
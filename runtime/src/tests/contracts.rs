@@ -0,0 +1,111 @@
+use super::*;
+use crate::chain_extension::{status, GET_SOMETHING, SET_SOMETHING};
+use frame_support::{
+	assert_ok,
+	traits::{fungible::InspectHold, Get},
+};
+use pallet_contracts::{CollectEvents, DebugInfo, Determinism};
+
+/// A contract forwarding its input to the chain extension, see `fixtures/template_extension.wat`.
+const FIXTURE: &[u8] = include_bytes!("../../fixtures/template_extension.wasm");
+
+const GAS_LIMIT: Weight = Weight::from_parts(100_000_000_000, 3 * 1024 * 1024);
+
+/// Upload and instantiate the fixture as `origin`, endowing the contract with `UNIT`.
+fn instantiate_fixture(origin: &AccountId) -> AccountId {
+	Contracts::bare_instantiate(
+		origin.clone(),
+		UNIT,
+		GAS_LIMIT,
+		None,
+		pallet_contracts::Code::Upload(FIXTURE.to_vec()),
+		vec![],
+		vec![],
+		DebugInfo::Skip,
+		CollectEvents::Skip,
+	)
+	.result
+	.expect("the fixture instantiates")
+	.account_id
+}
+
+/// Call chain extension function `func_id` with `input` through `contract`, returning the
+/// status code and the output of the function.
+fn call_extension(
+	origin: &AccountId,
+	contract: &AccountId,
+	func_id: u16,
+	input: impl Encode,
+) -> (u32, Vec<u8>) {
+	let data = [(func_id as u32).to_le_bytes().to_vec(), input.encode()].concat();
+	let output = Contracts::bare_call(
+		origin.clone(),
+		contract.clone(),
+		0,
+		GAS_LIMIT,
+		None,
+		data,
+		DebugInfo::Skip,
+		CollectEvents::Skip,
+		Determinism::Enforced,
+	)
+	.result
+	.expect("the contract call succeeds")
+	.data;
+	let (status, output) = output.split_at(4);
+	(u32::from_le_bytes(status.try_into().unwrap()), output.to_vec())
+}
+
+#[test]
+fn contracts_read_template_values() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, _] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+		let contract = instantiate_fixture(&alice);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(bob.clone()), 42));
+
+		let (status, output) = call_extension(&alice, &contract, GET_SOMETHING, &bob);
+		assert_eq!(status, status::SUCCESS);
+		assert_eq!(Option::<u32>::decode(&mut &output[..]).unwrap(), Some(42));
+
+		let (status, output) = call_extension(&alice, &contract, GET_SOMETHING, &alice);
+		assert_eq!(status, status::SUCCESS);
+		assert_eq!(Option::<u32>::decode(&mut &output[..]).unwrap(), None);
+	});
+}
+
+#[test]
+fn contracts_store_their_own_template_value() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+		let contract = instantiate_fixture(&alice);
+		let reason =
+			RuntimeHoldReason::TemplateModule(pallet_template::HoldReason::SomethingDeposit);
+
+		assert_eq!(call_extension(&alice, &contract, SET_SOMETHING, 7u32).0, status::SUCCESS);
+		assert_eq!(
+			pallet_template::Something::<Runtime>::get(&contract).map(|info| info.value),
+			Some(7)
+		);
+		// The deposit is held from the contract, not from the caller.
+		assert_eq!(
+			Balances::balance_on_hold(&reason, &contract),
+			<Runtime as pallet_template::Config>::SomethingDeposit::get()
+		);
+		assert_eq!(Balances::balance_on_hold(&reason, &alice), 0);
+
+		// Rejected values come back as status codes.
+		assert_eq!(call_extension(&alice, &contract, SET_SOMETHING, 7u32).0, status::UNCHANGED);
+		assert_eq!(
+			call_extension(&alice, &contract, SET_SOMETHING, u32::MAX).0,
+			status::VALUE_TOO_LARGE
+		);
+		assert_eq!(
+			call_extension(&alice, &contract, SET_SOMETHING, 0u32).0,
+			status::VALUE_TOO_SMALL
+		);
+		assert_eq!(
+			pallet_template::Something::<Runtime>::get(&contract).map(|info| info.value),
+			Some(7)
+		);
+	});
+}
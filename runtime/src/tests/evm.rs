@@ -0,0 +1,84 @@
+use super::*;
+use crate::evm::{evm_account_id, evm_address, WeightFeeGasPrice};
+use frame_support::assert_ok;
+use pallet_evm::FeeCalculator;
+use sp_core::{H160, U256};
+
+const TARGET: H160 = H160::repeat_byte(0x11);
+
+#[test]
+fn evm_balances_are_held_by_the_hashed_account() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+		assert_ok!(Balances::transfer_allow_death(
+			RuntimeOrigin::signed(alice),
+			evm_account_id(TARGET).into(),
+			UNIT
+		));
+
+		let (account, _) = EVM::account_basic(&TARGET);
+		assert_eq!(account.balance, U256::from(UNIT));
+	});
+}
+
+#[test]
+fn accounts_call_the_evm_as_their_truncated_address() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+		let source = evm_address(&alice);
+		assert_ok!(Balances::transfer_allow_death(
+			RuntimeOrigin::signed(alice.clone()),
+			evm_account_id(source).into(),
+			10 * UNIT
+		));
+
+		let (gas_price, _) = WeightFeeGasPrice::min_gas_price();
+		assert_ok!(EVM::call(
+			RuntimeOrigin::signed(alice.clone()),
+			source,
+			TARGET,
+			vec![],
+			U256::from(UNIT),
+			21_000,
+			gas_price,
+			None,
+			None,
+			vec![],
+		));
+		assert_eq!(Balances::free_balance(evm_account_id(TARGET)), UNIT);
+
+		// Others cannot act as Alice's address.
+		let bob = AUTHORITIES[1].0.to_account_id();
+		assert!(EVM::call(
+			RuntimeOrigin::signed(bob),
+			source,
+			TARGET,
+			vec![],
+			U256::from(UNIT),
+			21_000,
+			gas_price,
+			None,
+			None,
+			vec![],
+		)
+		.is_err());
+	});
+}
+
+#[test]
+fn withdraw_moves_evm_balance_back_to_the_caller() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+		let source = evm_address(&alice);
+		assert_ok!(Balances::transfer_allow_death(
+			RuntimeOrigin::signed(alice.clone()),
+			evm_account_id(source).into(),
+			2 * UNIT
+		));
+		let free = Balances::free_balance(&alice);
+
+		assert_ok!(EVM::withdraw(RuntimeOrigin::signed(alice.clone()), source, UNIT));
+		assert_eq!(Balances::free_balance(&alice), free + UNIT);
+		assert_eq!(Balances::free_balance(evm_account_id(source)), UNIT);
+	});
+}
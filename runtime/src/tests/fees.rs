@@ -0,0 +1,188 @@
+use super::*;
+use crate::dynamic_params::fees::{TargetBlockFullness, TransactionByteFee};
+use frame_support::{assert_ok, dispatch::DispatchClass, weights::WeightToFee as _};
+use sp_runtime::traits::Convert;
+
+/// The asset the fees of [`asset_fees_are_split_between_treasury_and_author`] are paid in.
+const ASSET: u32 = 1;
+
+/// The maximum weight the normal dispatch class may use in a block.
+fn max_normal_weight() -> Weight {
+	BlockWeights::get().get(DispatchClass::Normal).max_total.unwrap()
+}
+
+/// Simulate `blocks` blocks filled to `fullness` of the normal class and return the multiplier
+/// after each of them, starting from `multiplier`.
+fn multiplier_trajectory(
+	fullness: Perbill,
+	blocks: BlockNumber,
+	mut multiplier: Multiplier,
+) -> Vec<Multiplier> {
+	let weight = Weight::from_parts(fullness * max_normal_weight().ref_time(), 0);
+	(0..blocks)
+		.map(|_| {
+			System::set_block_consumed_resources(weight, 0);
+			multiplier = SlowAdjustingFeeUpdate::<Runtime>::convert(multiplier);
+			multiplier
+		})
+		.collect()
+}
+
+#[test]
+fn base_extrinsic_weight_costs_the_base_fee() {
+	assert_eq!(WeightToFee::weight_to_fee(&ExtrinsicBaseWeight::get()), BASE_EXTRINSIC_FEE);
+	assert_eq!(
+		WeightToFee::weight_to_fee(&(ExtrinsicBaseWeight::get() * 10)),
+		10 * BASE_EXTRINSIC_FEE
+	);
+}
+
+#[test]
+fn multiplier_is_stable_at_target_fullness() {
+	new_test_ext().execute_with(|| {
+		let target =
+			Perbill::from_rational(TargetBlockFullness::get().deconstruct(), Perquintill::ACCURACY);
+		let trajectory = multiplier_trajectory(target, 10, Multiplier::one());
+		assert!(trajectory.iter().all(|m| *m == Multiplier::one()));
+	});
+}
+
+#[test]
+fn multiplier_rises_steadily_under_full_blocks() {
+	new_test_ext().execute_with(|| {
+		let trajectory = multiplier_trajectory(Perbill::one(), DAYS, Multiplier::one());
+
+		// Fees go up with every full block...
+		assert!(trajectory.windows(2).all(|w| w[0] < w[1]));
+		// ...by about 3.4% per hour...
+		let after_an_hour = trajectory[HOURS as usize - 1];
+		assert!(after_an_hour > Multiplier::saturating_from_rational(103, 100));
+		assert!(after_an_hour < Multiplier::saturating_from_rational(104, 100));
+		// ...and compound to roughly e^0.81 after a day.
+		let after_a_day = *trajectory.last().unwrap();
+		assert!(after_a_day > Multiplier::saturating_from_rational(22, 10));
+		assert!(after_a_day < Multiplier::saturating_from_rational(23, 10));
+	});
+}
+
+#[test]
+fn multiplier_decays_under_empty_blocks_down_to_the_minimum() {
+	new_test_ext().execute_with(|| {
+		let trajectory = multiplier_trajectory(Perbill::zero(), DAYS, Multiplier::one());
+		assert!(trajectory.windows(2).all(|w| w[0] > w[1]));
+		assert!(*trajectory.last().unwrap() > MinimumMultiplier::get());
+
+		let trajectory = multiplier_trajectory(Perbill::zero(), 10, MinimumMultiplier::get());
+		assert!(trajectory.iter().all(|m| *m == MinimumMultiplier::get()));
+	});
+}
+
+#[test]
+fn multiplier_recovers_from_the_minimum() {
+	new_test_ext().execute_with(|| {
+		let trajectory = multiplier_trajectory(Perbill::one(), 10, MinimumMultiplier::get());
+		assert!(trajectory[0] > MinimumMultiplier::get());
+		assert!(trajectory.windows(2).all(|w| w[0] < w[1]));
+	});
+}
+
+#[test]
+fn fees_are_split_between_treasury_and_author() {
+	new_test_ext().execute_with(|| {
+		initialize_block_in_slot(slot_of(0, 0));
+		let author = AUTHORITIES[0].0.to_account_id();
+		let treasury = Balances::free_balance(TreasuryAccount::get());
+
+		DealWithFees::on_unbalanceds(vec![Balances::issue(10 * UNIT)].into_iter());
+
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury + 8 * UNIT);
+		assert_eq!(Balances::free_balance(&author), INITIAL_BALANCE + 2 * UNIT);
+	});
+}
+
+#[test]
+fn asset_fees_are_split_between_treasury_and_author() {
+	new_test_ext().execute_with(|| {
+		initialize_block_in_slot(slot_of(0, 0));
+		let author = AUTHORITIES[0].0.to_account_id();
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			ASSET.into(),
+			author.clone().into(),
+			true,
+			EXISTENTIAL_DEPOSIT
+		));
+
+		DealWithAssetFees::handle_credit(Assets::issue(ASSET, 10 * UNIT));
+
+		assert_eq!(Assets::balance(ASSET, TreasuryAccount::get()), 8 * UNIT);
+		assert_eq!(Assets::balance(ASSET, &author), 2 * UNIT);
+		assert_eq!(Assets::total_supply(ASSET), 10 * UNIT);
+
+		// With a tip of 3 units in a fee of 13, of which 15 were withdrawn up front, the tip goes
+		// to the author in full and the rest of the fee is split as before.
+		let payer = Sr25519Keyring::Dave.to_account_id();
+		assert_ok!(ChargeAssetFees::correct_and_deposit_fee(
+			&payer,
+			&Default::default(),
+			&Default::default(),
+			13 * UNIT,
+			3 * UNIT,
+			Assets::issue(ASSET, 15 * UNIT),
+		));
+
+		assert_eq!(Assets::balance(ASSET, TreasuryAccount::get()), 16 * UNIT);
+		assert_eq!(Assets::balance(ASSET, &author), 7 * UNIT);
+		assert_eq!(Assets::balance(ASSET, &payer), 2 * UNIT);
+		assert_eq!(Assets::total_supply(ASSET), 25 * UNIT);
+	});
+}
+
+#[test]
+fn tips_go_to_the_author_in_full() {
+	new_test_ext().execute_with(|| {
+		initialize_block_in_slot(slot_of(1, 0));
+		let author = AUTHORITIES[1].0.to_account_id();
+		let treasury = Balances::free_balance(TreasuryAccount::get());
+
+		DealWithFees::on_unbalanceds(
+			vec![Balances::issue(10 * UNIT), Balances::issue(3 * UNIT)].into_iter(),
+		);
+
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury + 8 * UNIT);
+		assert_eq!(Balances::free_balance(&author), INITIAL_BALANCE + 2 * UNIT + 3 * UNIT);
+		// The other authorities get nothing.
+		assert_eq!(Balances::free_balance(AUTHORITIES[0].0.to_account_id()), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn multiplier_scales_the_weight_fee() {
+	new_test_ext().execute_with(|| {
+		let info = frame_support::dispatch::DispatchInfo {
+			weight: ExtrinsicBaseWeight::get(),
+			..Default::default()
+		};
+		let fee = |multiplier: Multiplier| {
+			pallet_transaction_payment::NextFeeMultiplier::<Runtime>::put(multiplier);
+			TransactionPayment::compute_fee(0, &info, 0)
+		};
+		// The multiplier applies to the weight fee, on top of the unadjusted base fee.
+		assert_eq!(fee(Multiplier::one()), 2 * BASE_EXTRINSIC_FEE);
+		assert_eq!(fee(Multiplier::saturating_from_integer(2)), 3 * BASE_EXTRINSIC_FEE);
+		assert_eq!(fee(MinimumMultiplier::get()), BASE_EXTRINSIC_FEE + BASE_EXTRINSIC_FEE / 10);
+	});
+}
+
+#[test]
+fn length_fee_is_charged_per_byte() {
+	new_test_ext().execute_with(|| {
+		let info = frame_support::dispatch::DispatchInfo::default();
+		let length_fee = |len| {
+			TransactionPayment::compute_fee(len, &info, 0) -
+				TransactionPayment::compute_fee(0, &info, 0)
+		};
+		assert_eq!(length_fee(1), TransactionByteFee::get());
+		assert_eq!(length_fee(1_000), 1_000 * MICRO_UNIT);
+	});
+}
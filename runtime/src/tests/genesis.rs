@@ -0,0 +1,95 @@
+use super::*;
+use crate::{
+	genesis_config_presets::{DEV_RUNTIME_PRESET, STAGING_RUNTIME_PRESET},
+	genesis_presets_api::runtime_decl_for_genesis_presets_api::GenesisPresetsApiV1,
+};
+use frame_support::genesis_builder_helper::{build_config, create_default_config};
+use serde_json::Value;
+
+/// Apply `patch` to `config`, the way chain specs apply a genesis config patch.
+fn merge(config: &mut Value, patch: Value) {
+	match (config, patch) {
+		(Value::Object(config), Value::Object(patch)) =>
+			for (key, value) in patch {
+				merge(config.entry(key).or_insert(Value::Null), value);
+			},
+		(config, patch) => *config = patch,
+	}
+}
+
+/// Build the genesis storage of the default config patched with `patch`.
+fn build_patched(patch: Value) -> sp_genesis_builder::Result {
+	let mut config: Value =
+		serde_json::from_slice(&create_default_config::<RuntimeGenesisConfig>()).unwrap();
+	merge(&mut config, patch);
+	sp_io::TestExternalities::default()
+		.execute_with(|| build_config::<RuntimeGenesisConfig>(serde_json::to_vec(&config).unwrap()))
+}
+
+/// The preset `id`, read through the runtime API.
+fn get_preset(id: &str) -> Option<Vec<u8>> {
+	<Runtime as GenesisPresetsApiV1<Block>>::get_preset(id.as_bytes().to_vec())
+}
+
+fn preset(id: &str) -> Value {
+	serde_json::from_slice(&get_preset(id).unwrap()).unwrap()
+}
+
+/// The names of all presets, read through the runtime API.
+fn preset_names() -> Vec<String> {
+	<Runtime as GenesisPresetsApiV1<Block>>::preset_names()
+		.into_iter()
+		.map(|id| String::from_utf8(id).unwrap())
+		.collect()
+}
+
+#[test]
+fn development_presets_build() {
+	let names = preset_names();
+	assert!(names.iter().any(|id| id == DEV_RUNTIME_PRESET));
+	for id in names.iter().filter(|id| *id != STAGING_RUNTIME_PRESET) {
+		assert_eq!(build_patched(preset(id)), Ok(()), "preset `{}`", id);
+	}
+}
+
+#[test]
+fn development_stash_accounts_are_endowed() {
+	use sp_core::{sr25519, Pair};
+
+	let endowed: Vec<AccountId> = serde_json::from_value::<Vec<(AccountId, u64)>>(
+		preset(DEV_RUNTIME_PRESET)["balances"]["balances"].clone(),
+	)
+	.unwrap()
+	.into_iter()
+	.map(|(who, _)| who)
+	.collect();
+	for seed in ["//Alice//stash", "//Bob//stash"] {
+		let stash = AccountId::from(sr25519::Pair::from_string(seed, None).unwrap().public());
+		assert!(endowed.contains(&stash), "`{}` is not endowed", seed);
+	}
+}
+
+#[test]
+fn staging_preset_builds_once_authorities_are_set_and_bonded() {
+	let alice = Sr25519Keyring::Alice.to_account_id();
+	let keys = opaque::SessionKeys {
+		aura: Sr25519Keyring::Alice.public().into(),
+		grandpa: Ed25519Keyring::Alice.public().into(),
+	};
+
+	let mut patch = preset(STAGING_RUNTIME_PRESET);
+	merge(
+		&mut patch,
+		serde_json::json!({
+			"balances": { "balances": [(alice.clone(), INITIAL_BALANCE + ValidatorBond::get())] },
+			"validatorSet": { "initialValidators": [alice.clone()] },
+			"session": { "keys": [(alice.clone(), alice, keys)] },
+		}),
+	);
+	assert_eq!(build_patched(patch), Ok(()));
+}
+
+#[test]
+fn unknown_preset_is_none() {
+	assert_eq!(get_preset("unknown"), None);
+}
@@ -0,0 +1,71 @@
+use super::*;
+use frame_support::{assert_ok, dispatch::GetDispatchInfo, traits::OnRuntimeUpgrade};
+use sp_runtime::{traits::Hash, DispatchError};
+
+fn add_validator_call(who: AccountId) -> RuntimeCall {
+	RuntimeCall::ValidatorSet(pallet_validator_set::Call::add_validator { who })
+}
+
+#[test]
+fn council_majority_manages_validators() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, _] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+		let dave = Sr25519Keyring::Dave.to_account_id();
+		let call = add_validator_call(dave.clone());
+		let hash = BlakeTwo256::hash_of(&call);
+		let length = call.encoded_size() as u32;
+		let weight = call.get_dispatch_info().weight;
+
+		assert_ok!(Council::propose(
+			RuntimeOrigin::signed(alice.clone()),
+			2,
+			Box::new(call),
+			length
+		));
+		assert_ok!(Council::vote(RuntimeOrigin::signed(alice.clone()), hash, 0, true));
+		assert_ok!(Council::vote(RuntimeOrigin::signed(bob), hash, 0, true));
+		assert_ok!(Council::close(RuntimeOrigin::signed(alice), hash, 0, weight, length));
+
+		assert!(pallet_validator_set::Validators::<Runtime>::get().contains(&dave));
+	});
+}
+
+#[test]
+fn council_minority_cannot_manage_validators() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+		let dave = Sr25519Keyring::Dave.to_account_id();
+		let call = add_validator_call(dave.clone());
+		let length = call.encoded_size() as u32;
+
+		// A threshold of one executes right away, with the backing of a third of the council.
+		assert_ok!(Council::propose(
+			RuntimeOrigin::signed(alice),
+			1,
+			Box::new(call.clone()),
+			length
+		));
+
+		System::assert_last_event(
+			pallet_collective::Event::<Runtime, CouncilCollective>::Executed {
+				proposal_hash: BlakeTwo256::hash_of(&call),
+				result: Err(DispatchError::BadOrigin),
+			}
+			.into(),
+		);
+		assert!(!pallet_validator_set::Validators::<Runtime>::get().contains(&dave));
+	});
+}
+
+#[test]
+fn remove_sudo_migration_clears_the_sudo_key() {
+	new_test_ext().execute_with(|| {
+		let key = frame_support::storage::storage_prefix(b"Sudo", b"Key");
+		frame_support::storage::unhashed::put(&key, &AUTHORITIES[0].0.to_account_id());
+		assert_eq!(Sudo::key(), Some(AUTHORITIES[0].0.to_account_id()));
+
+		RemoveSudo::on_runtime_upgrade();
+
+		assert_eq!(Sudo::key(), None);
+	});
+}
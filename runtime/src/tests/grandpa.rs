@@ -0,0 +1,147 @@
+use super::*;
+use frame_support::{assert_err, assert_ok};
+use sp_consensus_grandpa::{EquivocationProof, RoundNumber, SetId, KEY_TYPE};
+use sp_core::H256;
+
+/// Build a proof that `keyring` prevoted for two different blocks in the same round.
+pub(super) fn generate_equivocation_proof(
+	set_id: SetId,
+	round: RoundNumber,
+	keyring: Ed25519Keyring,
+) -> EquivocationProof<Hash, BlockNumber> {
+	let signed_prevote = |target_hash: Hash| {
+		let prevote = finality_grandpa::Prevote { target_hash, target_number: 1 };
+		let message = finality_grandpa::Message::Prevote(prevote.clone());
+		let payload = sp_consensus_grandpa::localized_payload(round, set_id, &message);
+		(prevote, keyring.sign(&payload).into())
+	};
+
+	EquivocationProof::new(
+		set_id,
+		sp_consensus_grandpa::Equivocation::Prevote(finality_grandpa::Equivocation {
+			round_number: round,
+			identity: keyring.public().into(),
+			first: signed_prevote(H256::repeat_byte(1)),
+			second: signed_prevote(H256::repeat_byte(2)),
+		}),
+	)
+}
+
+pub(super) fn key_owner_proof(
+	keyring: Ed25519Keyring,
+) -> <Runtime as pallet_grandpa::Config>::KeyOwnerProof {
+	Historical::prove((KEY_TYPE, GrandpaId::from(keyring.public()))).unwrap()
+}
+
+/// The validator bond `who` currently has on hold.
+fn held_bond(who: &AccountId) -> Balance {
+	let reason = RuntimeHoldReason::ValidatorSet(pallet_validator_set::HoldReason::ValidatorBond);
+	Balances::balance_on_hold(&reason, who)
+}
+
+fn offence_reported() -> bool {
+	System::events()
+		.iter()
+		.any(|r| matches!(r.event, RuntimeEvent::Offences(pallet_offences::Event::Offence { .. })))
+}
+
+#[test]
+fn equivocation_in_current_session_is_reported_and_slashed() {
+	new_test_ext().execute_with(|| {
+		start_session(2);
+		let (offender, keyring) = AUTHORITIES[0];
+		let offender = offender.to_account_id();
+		let issuance = Balances::total_issuance();
+
+		let equivocation_proof = generate_equivocation_proof(Grandpa::current_set_id(), 1, keyring);
+		assert_ok!(Grandpa::report_equivocation_unsigned(
+			RuntimeOrigin::none(),
+			Box::new(equivocation_proof),
+			key_owner_proof(keyring),
+		));
+
+		assert!(offence_reported());
+		// One offender out of three validators is slashed 100%, which burns the whole bond...
+		assert_eq!(held_bond(&offender), 0);
+		assert_eq!(Balances::total_issuance(), issuance - ValidatorBond::get());
+		// ...but nothing more.
+		assert_eq!(Balances::free_balance(&offender), INITIAL_BALANCE);
+		// Only the offender is punished.
+		let other = AUTHORITIES[1].0.to_account_id();
+		assert_eq!(held_bond(&other), ValidatorBond::get());
+		assert_eq!(Balances::free_balance(&other), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn equivocation_in_past_session_is_reported_through_historical_proof() {
+	new_test_ext().execute_with(|| {
+		start_session(2);
+		let (offender, keyring) = AUTHORITIES[1];
+
+		// The equivocation and the ownership proof date from session 2...
+		let equivocation_proof = generate_equivocation_proof(Grandpa::current_set_id(), 1, keyring);
+		let key_owner_proof = key_owner_proof(keyring);
+
+		// ...but the report only lands once session 2 is over, so the proof has to be checked
+		// against the historical session root.
+		start_session(3);
+		assert_ok!(Grandpa::report_equivocation_unsigned(
+			RuntimeOrigin::none(),
+			Box::new(equivocation_proof),
+			key_owner_proof,
+		));
+
+		assert!(offence_reported());
+		assert_eq!(held_bond(&offender.to_account_id()), 0);
+	});
+}
+
+#[test]
+fn offenders_lose_the_slashed_fraction_of_their_bond() {
+	new_test_ext().execute_with(|| {
+		let offender = AUTHORITIES[0].0.to_account_id();
+		let details =
+			OffenceDetails { offender: (offender.clone(), offender.clone()), reporters: vec![] };
+		SlashOffenders::on_offence(
+			&[details.clone()],
+			&[Perbill::from_percent(10)],
+			Session::current_index(),
+			DisableStrategy::Never,
+		);
+		assert_eq!(held_bond(&offender), ValidatorBond::get() / 10 * 9);
+
+		// Later offences slash a fraction of what is left.
+		SlashOffenders::on_offence(
+			&[details],
+			&[Perbill::from_percent(50)],
+			Session::current_index(),
+			DisableStrategy::Never,
+		);
+		assert_eq!(held_bond(&offender), ValidatorBond::get() / 20 * 9);
+		assert_eq!(Balances::free_balance(&offender), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn duplicate_equivocation_report_is_rejected() {
+	new_test_ext().execute_with(|| {
+		start_session(2);
+		let (_, keyring) = AUTHORITIES[2];
+		let set_id = Grandpa::current_set_id();
+
+		assert_ok!(Grandpa::report_equivocation_unsigned(
+			RuntimeOrigin::none(),
+			Box::new(generate_equivocation_proof(set_id, 1, keyring)),
+			key_owner_proof(keyring),
+		));
+		assert_err!(
+			Grandpa::report_equivocation_unsigned(
+				RuntimeOrigin::none(),
+				Box::new(generate_equivocation_proof(set_id, 1, keyring)),
+				key_owner_proof(keyring),
+			),
+			pallet_grandpa::Error::<Runtime>::DuplicateOffenceReport
+		);
+	});
+}
@@ -0,0 +1,49 @@
+use super::*;
+use frame_support::{
+	assert_ok,
+	traits::{fungible::InspectHold, Get},
+};
+use sp_runtime::traits::Hash as _;
+
+#[test]
+fn template_deposit_is_held_under_its_runtime_reason() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+		let reason =
+			RuntimeHoldReason::TemplateModule(pallet_template::HoldReason::SomethingDeposit);
+		let deposit = <Runtime as pallet_template::Config>::SomethingDeposit::get();
+
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(alice.clone()), 42));
+		assert_eq!(Balances::balance_on_hold(&reason, &alice), deposit);
+		// Alice is a validator, so her bond is held next to the deposit.
+		assert_eq!(Balances::total_balance_on_hold(&alice), deposit + ValidatorBond::get());
+		assert_eq!(Balances::free_balance(&alice), INITIAL_BALANCE - deposit);
+
+		assert_ok!(TemplateModule::clear_something(RuntimeOrigin::signed(alice.clone())));
+		assert_eq!(Balances::balance_on_hold(&reason, &alice), 0);
+		assert_eq!(Balances::free_balance(&alice), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn preimage_deposit_is_held_under_its_runtime_reason() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+		let reason = RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+		let preimage = vec![1u8; 100];
+		let hash = BlakeTwo256::hash(&preimage);
+		let deposit = PreimageBaseDeposit::get() + 100 * PreimageByteDeposit::get();
+
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(alice.clone()), preimage));
+		assert_eq!(Balances::balance_on_hold(&reason, &alice), deposit);
+		assert_eq!(Balances::free_balance(&alice), INITIAL_BALANCE - deposit);
+
+		assert_ok!(Preimage::unnote_preimage(RuntimeOrigin::signed(alice.clone()), hash));
+		assert_eq!(Balances::balance_on_hold(&reason, &alice), 0);
+		assert_eq!(Balances::free_balance(&alice), INITIAL_BALANCE);
+
+		// The manager origin notes preimages without a deposit.
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::root(), vec![2u8; 100]));
+		assert_eq!(Balances::total_balance_on_hold(&alice), ValidatorBond::get());
+	});
+}
@@ -0,0 +1,103 @@
+//! Tests that exercise the pallets together, as configured in this runtime.
+
+mod assets;
+mod aura;
+mod contracts;
+#[cfg(feature = "evm")]
+mod evm;
+mod fees;
+mod genesis;
+mod governance;
+mod grandpa;
+mod holds;
+mod multisig;
+mod parameters;
+mod proxy;
+mod scheduler;
+mod utility;
+mod validator_set;
+mod vesting;
+
+use crate::*;
+use frame_support::traits::{OnFinalize, OnInitialize};
+use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
+use sp_runtime::{Digest, DigestItem};
+
+/// The validators of the test chain: the account and Aura key come from the `Sr25519Keyring`, the
+/// GRANDPA key from the `Ed25519Keyring`.
+pub const AUTHORITIES: [(Sr25519Keyring, Ed25519Keyring); 3] = [
+	(Sr25519Keyring::Alice, Ed25519Keyring::Alice),
+	(Sr25519Keyring::Bob, Ed25519Keyring::Bob),
+	(Sr25519Keyring::Charlie, Ed25519Keyring::Charlie),
+];
+
+/// The free balance every authority starts with, next to its held [`ValidatorBond`].
+pub const INITIAL_BALANCE: Balance = 1 << 60;
+
+/// Build the genesis storage of a chain run by [`AUTHORITIES`].
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	new_test_ext_with(test_genesis())
+}
+
+/// The genesis config of a chain run by [`AUTHORITIES`], for tests that need to extend it.
+pub fn test_genesis() -> RuntimeGenesisConfig {
+	let keys = AUTHORITIES
+		.iter()
+		.map(|(sr, ed)| {
+			let who = sr.to_account_id();
+			let keys =
+				opaque::SessionKeys { aura: sr.public().into(), grandpa: ed.public().into() };
+			(who.clone(), who, keys)
+		})
+		.collect();
+	let initial_validators: Vec<_> = AUTHORITIES.iter().map(|(sr, _)| sr.to_account_id()).collect();
+	let balances = AUTHORITIES
+		.iter()
+		.map(|(sr, _)| (sr.to_account_id(), INITIAL_BALANCE + ValidatorBond::get()))
+		.collect();
+
+	RuntimeGenesisConfig {
+		balances: BalancesConfig { balances },
+		// The authorities double as the council.
+		council: CouncilConfig { members: initial_validators.clone(), ..Default::default() },
+		validator_set: ValidatorSetConfig { initial_validators },
+		session: SessionConfig { keys, ..Default::default() },
+		..Default::default()
+	}
+}
+
+/// Build the genesis storage from `genesis`.
+pub fn new_test_ext_with(genesis: RuntimeGenesisConfig) -> sp_io::TestExternalities {
+	let storage = genesis.build_storage().unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Rotate sessions, one per block, until `index` is the current session.
+pub fn start_session(index: SessionIndex) {
+	while Session::current_index() < index {
+		let block_number = System::block_number() + 1;
+		System::set_block_number(block_number);
+		Session::rotate_session();
+		// Enact the authority set change GRANDPA schedules on each new session.
+		Grandpa::on_finalize(block_number);
+	}
+}
+
+/// Start the next block, claiming `slot` in the Aura pre-runtime digest as an author would.
+pub fn initialize_block_in_slot(slot: u64) {
+	let number = System::block_number() + 1;
+	let digest =
+		Digest { logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, Slot::from(slot).encode())] };
+	System::initialize(&number, &System::parent_hash(), &digest);
+	Aura::on_initialize(number);
+}
+
+/// The first slot after `after` that belongs to the authority at `index`.
+pub fn slot_of(index: usize, after: u64) -> u64 {
+	let n = AUTHORITIES.len() as u64;
+	(after / n + 1) * n + index as u64
+}
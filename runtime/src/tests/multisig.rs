@@ -0,0 +1,54 @@
+use super::*;
+use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+use sp_core::hashing::blake2_256;
+
+#[test]
+fn two_of_three_multisig_transfers_once_approved() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, charlie] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+		let dave = Sr25519Keyring::Dave.to_account_id();
+		let multisig =
+			Multisig::multi_account_id(&[alice.clone(), bob.clone(), charlie.clone()], 2);
+		Balances::make_free_balance_be(&multisig, 1_000 * EXISTENTIAL_DEPOSIT);
+
+		let call = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: dave.clone().into(),
+			value: 10 * EXISTENTIAL_DEPOSIT,
+		});
+		let others = |me: &AccountId| {
+			let mut others: Vec<_> =
+				[&alice, &bob, &charlie].into_iter().filter(|a| *a != me).cloned().collect();
+			others.sort();
+			others
+		};
+
+		// The first approval only records the call hash and reserves a deposit.
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(alice.clone()),
+			2,
+			others(&alice),
+			None,
+			blake2_256(&call.encode()),
+			Weight::zero(),
+		));
+		let timepoint = Multisig::timepoint();
+		assert_eq!(
+			Balances::reserved_balance(&alice),
+			DepositBase::get() + 2 * DepositFactor::get()
+		);
+		assert_eq!(Balances::free_balance(&dave), 0);
+
+		// The second one reaches the threshold and dispatches the call.
+		let weight = call.get_dispatch_info().weight;
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(bob.clone()),
+			2,
+			others(&bob),
+			Some(timepoint),
+			Box::new(call),
+			weight,
+		));
+		assert_eq!(Balances::free_balance(&dave), 10 * EXISTENTIAL_DEPOSIT);
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+	});
+}
@@ -0,0 +1,117 @@
+use super::*;
+use crate::dynamic_params::{fees, template};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::InspectHold, Get},
+};
+use sp_runtime::DispatchError;
+
+fn set_parameter(parameter: RuntimeParameters) {
+	assert_ok!(Parameters::set_parameter(RuntimeOrigin::root(), parameter));
+}
+
+#[test]
+fn root_tunes_fees() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TransactionPayment::length_to_fee(10), 10 * MICRO_UNIT);
+
+		set_parameter(RuntimeParameters::Fees(fees::Parameters::TransactionByteFee(
+			fees::TransactionByteFee,
+			Some(2 * MICRO_UNIT),
+		)));
+		assert_eq!(TransactionPayment::length_to_fee(10), 20 * MICRO_UNIT);
+
+		set_parameter(RuntimeParameters::Fees(fees::Parameters::OperationalFeeMultiplier(
+			fees::OperationalFeeMultiplier,
+			Some(10),
+		)));
+		assert_eq!(
+			<Runtime as pallet_transaction_payment::Config>::OperationalFeeMultiplier::get(),
+			10
+		);
+
+		// Removing a value falls back to the default.
+		set_parameter(RuntimeParameters::Fees(fees::Parameters::TransactionByteFee(
+			fees::TransactionByteFee,
+			None,
+		)));
+		assert_eq!(TransactionPayment::length_to_fee(10), 10 * MICRO_UNIT);
+	});
+}
+
+#[test]
+fn root_tunes_template_limits() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, _] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+
+		set_parameter(RuntimeParameters::Template(template::Parameters::MaxValue(
+			template::MaxValue,
+			Some(100),
+		)));
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(alice.clone()), 101),
+			pallet_template::Error::<Runtime>::ValueTooLarge
+		);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(alice), 100));
+
+		// New values pay the new deposit.
+		set_parameter(RuntimeParameters::Template(template::Parameters::SomethingDeposit(
+			template::SomethingDeposit,
+			Some(UNIT),
+		)));
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(bob.clone()), 42));
+		let reason =
+			RuntimeHoldReason::TemplateModule(pallet_template::HoldReason::SomethingDeposit);
+		assert_eq!(Balances::balance_on_hold(&reason, &bob), UNIT);
+	});
+}
+
+#[test]
+fn template_min_value_never_exceeds_max_value() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+
+		set_parameter(RuntimeParameters::Template(template::Parameters::MaxValue(
+			template::MaxValue,
+			Some(100),
+		)));
+		set_parameter(RuntimeParameters::Template(template::Parameters::MinValue(
+			template::MinValue,
+			Some(500),
+		)));
+		assert_eq!(<Runtime as pallet_template::Config>::MinValue::get(), 100);
+
+		// Only the maximum can be stored until the bounds are set the right way around again.
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(alice.clone()), 99),
+			pallet_template::Error::<Runtime>::ValueTooSmall
+		);
+		assert_noop!(
+			TemplateModule::do_something(RuntimeOrigin::signed(alice.clone()), 101),
+			pallet_template::Error::<Runtime>::ValueTooLarge
+		);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(alice), 100));
+
+		set_parameter(RuntimeParameters::Template(template::Parameters::MinValue(
+			template::MinValue,
+			Some(10),
+		)));
+		assert_eq!(<Runtime as pallet_template::Config>::MinValue::get(), 10);
+	});
+}
+
+#[test]
+fn only_root_sets_parameters() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+		let parameter = RuntimeParameters::Template(template::Parameters::MaxValue(
+			template::MaxValue,
+			Some(100),
+		));
+
+		assert_noop!(
+			Parameters::set_parameter(RuntimeOrigin::signed(alice), parameter),
+			DispatchError::BadOrigin
+		);
+	});
+}
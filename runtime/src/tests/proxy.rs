@@ -0,0 +1,142 @@
+use super::*;
+use frame_support::{assert_ok, traits::InstanceFilter};
+use sp_runtime::{traits::Hash, DispatchResult};
+
+/// Let `delegate` act for `real` with the given proxy type.
+fn add_proxy(real: &AccountId, delegate: &AccountId, proxy_type: ProxyType) {
+	assert_ok!(Proxy::add_proxy(
+		RuntimeOrigin::signed(real.clone()),
+		delegate.clone().into(),
+		proxy_type,
+		0
+	));
+}
+
+/// Dispatch `call` through the proxy and return the result of the proxied call.
+fn proxy(delegate: &AccountId, real: &AccountId, call: RuntimeCall) -> DispatchResult {
+	assert_ok!(Proxy::proxy(
+		RuntimeOrigin::signed(delegate.clone()),
+		real.clone().into(),
+		None,
+		Box::new(call)
+	));
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|r| match r.event {
+			RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted { result }) => Some(result),
+			_ => None,
+		})
+		.expect("a proxied call always emits `ProxyExecuted`; qed")
+}
+
+fn transfer(dest: &AccountId) -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+		dest: dest.clone().into(),
+		value: 100,
+	})
+}
+
+fn do_something() -> RuntimeCall {
+	RuntimeCall::TemplateModule(pallet_template::Call::do_something { something: 42 })
+}
+
+fn call_filtered() -> DispatchResult {
+	Err(frame_system::Error::<Runtime>::CallFiltered.into())
+}
+
+#[test]
+fn any_proxy_can_transfer() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, charlie] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+		add_proxy(&alice, &bob, ProxyType::Any);
+
+		assert_ok!(proxy(&bob, &alice, transfer(&charlie)));
+		assert_eq!(Balances::free_balance(&charlie), INITIAL_BALANCE + 100);
+	});
+}
+
+#[test]
+fn non_transfer_proxy_cannot_move_funds() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, charlie] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+		add_proxy(&alice, &bob, ProxyType::NonTransfer);
+
+		assert_eq!(proxy(&bob, &alice, transfer(&charlie)), call_filtered());
+		let asset_transfer = RuntimeCall::Assets(pallet_assets::Call::transfer {
+			id: 1.into(),
+			target: charlie.clone().into(),
+			amount: 100,
+		});
+		assert_eq!(proxy(&bob, &alice, asset_transfer), call_filtered());
+		assert_ok!(proxy(&bob, &alice, do_something()));
+		assert!(pallet_template::Something::<Runtime>::get(&alice).is_some());
+	});
+}
+
+#[test]
+fn filter_applies_to_batched_calls() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, charlie] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+		add_proxy(&alice, &bob, ProxyType::NonTransfer);
+
+		let batch = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+			calls: vec![do_something(), transfer(&charlie)],
+		});
+		assert_eq!(proxy(&bob, &alice, batch), call_filtered());
+		assert_eq!(Balances::free_balance(&charlie), INITIAL_BALANCE);
+		assert!(pallet_template::Something::<Runtime>::get(&alice).is_none());
+	});
+}
+
+#[test]
+fn template_proxy_only_reaches_the_template_pallet() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, charlie] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+		add_proxy(&alice, &bob, ProxyType::Template);
+
+		assert_ok!(proxy(&bob, &alice, do_something()));
+		assert_eq!(proxy(&bob, &alice, transfer(&charlie)), call_filtered());
+	});
+}
+
+#[test]
+fn governance_proxy_can_vote_in_council() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, charlie] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+		let dave = Sr25519Keyring::Dave.to_account_id();
+		add_proxy(&alice, &dave, ProxyType::Governance);
+
+		let motion = RuntimeCall::ValidatorSet(pallet_validator_set::Call::add_validator {
+			who: dave.clone(),
+		});
+		let hash = BlakeTwo256::hash_of(&motion);
+		assert_ok!(Council::propose(
+			RuntimeOrigin::signed(bob),
+			2,
+			Box::new(motion.clone()),
+			motion.encoded_size() as u32
+		));
+
+		let vote = RuntimeCall::Council(pallet_collective::Call::vote {
+			proposal: hash,
+			index: 0,
+			approve: true,
+		});
+		assert_ok!(proxy(&dave, &alice, vote));
+		assert_eq!(Council::voting(hash).unwrap().ayes, vec![alice.clone()]);
+		assert_eq!(proxy(&dave, &alice, transfer(&charlie)), call_filtered());
+	});
+}
+
+#[test]
+fn proxy_types_are_ordered_by_what_they_allow() {
+	use ProxyType::*;
+	assert!(Any.is_superset(&NonTransfer));
+	assert!(Any.is_superset(&Governance));
+	assert!(NonTransfer.is_superset(&Governance));
+	assert!(NonTransfer.is_superset(&Template));
+	assert!(!NonTransfer.is_superset(&Any));
+	assert!(!Governance.is_superset(&Template));
+	assert!(!Template.is_superset(&Governance));
+}
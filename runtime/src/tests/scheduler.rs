@@ -0,0 +1,115 @@
+use super::*;
+use crate::scheduler_api::runtime_decl_for_scheduler_api::SchedulerApiV1;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+type ScheduledOf = pallet_scheduler::ScheduledOf<Runtime>;
+
+fn agenda(when: BlockNumber) -> Vec<(u32, ScheduledOf)> {
+	<Runtime as SchedulerApiV1<Block, BlockNumber, ScheduledOf>>::agenda(when)
+}
+
+fn scheduled_blocks() -> Vec<(BlockNumber, u32)> {
+	<Runtime as SchedulerApiV1<Block, BlockNumber, ScheduledOf>>::scheduled_blocks()
+}
+
+/// Advance to block `n`, servicing the scheduler agenda of every block on the way.
+fn run_to_block(n: BlockNumber) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		Scheduler::on_initialize(next);
+	}
+}
+
+/// The number of tasks the scheduler has dispatched so far.
+fn dispatched() -> usize {
+	System::events()
+		.iter()
+		.filter(|record| {
+			matches!(
+				record.event,
+				RuntimeEvent::Scheduler(pallet_scheduler::Event::Dispatched { result: Ok(()), .. })
+			)
+		})
+		.count()
+}
+
+fn remark() -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }))
+}
+
+#[test]
+fn named_task_runs_at_its_block_unless_cancelled() {
+	new_test_ext().execute_with(|| {
+		let dave = Sr25519Keyring::Dave.to_account_id();
+		let set_balance =
+			Box::new(RuntimeCall::Balances(pallet_balances::Call::force_set_balance {
+				who: dave.clone().into(),
+				new_free: UNIT,
+			}));
+		let id = [1u8; 32];
+
+		assert_ok!(Scheduler::schedule_named(
+			RuntimeOrigin::root(),
+			id,
+			5,
+			None,
+			0,
+			set_balance.clone()
+		));
+		assert_eq!(agenda(5).len(), 1);
+		assert_eq!(agenda(5)[0].0, 0);
+		assert_eq!(scheduled_blocks(), vec![(5, 1)]);
+
+		// A cancelled task leaves the agenda and never runs.
+		assert_ok!(Scheduler::cancel_named(RuntimeOrigin::root(), id));
+		assert!(agenda(5).is_empty());
+		assert!(scheduled_blocks().is_empty());
+		run_to_block(5);
+		assert_eq!(Balances::free_balance(&dave), 0);
+
+		// The name can be reused once the task is gone.
+		assert_ok!(Scheduler::schedule_named(RuntimeOrigin::root(), id, 8, None, 0, set_balance));
+		run_to_block(7);
+		assert_eq!(Balances::free_balance(&dave), 0);
+		run_to_block(8);
+		assert_eq!(Balances::free_balance(&dave), UNIT);
+		assert!(scheduled_blocks().is_empty());
+	});
+}
+
+#[test]
+fn periodic_task_runs_the_given_number_of_times() {
+	new_test_ext().execute_with(|| {
+		// Every second block from block 5 on, three times in total.
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::root(), 5, Some((2, 3)), 0, remark()));
+
+		run_to_block(5);
+		assert_eq!(dispatched(), 1);
+		// The next run is rescheduled into the agenda of its block.
+		assert_eq!(scheduled_blocks(), vec![(7, 1)]);
+
+		run_to_block(6);
+		assert_eq!(dispatched(), 1);
+		run_to_block(12);
+		assert_eq!(dispatched(), 3);
+		assert!(scheduled_blocks().is_empty());
+	});
+}
+
+#[test]
+fn only_root_can_schedule() {
+	new_test_ext().execute_with(|| {
+		let alice = AUTHORITIES[0].0.to_account_id();
+
+		assert_noop!(
+			Scheduler::schedule(RuntimeOrigin::signed(alice.clone()), 5, None, 0, remark()),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Scheduler::schedule_named(RuntimeOrigin::signed(alice), [1; 32], 5, None, 0, remark()),
+			DispatchError::BadOrigin
+		);
+	});
+}
@@ -0,0 +1,67 @@
+use super::*;
+use frame_support::{assert_err_ignore_postinfo, assert_ok};
+use sp_runtime::TokenError;
+
+fn transfer(dest: &AccountId, value: Balance) -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+		dest: dest.clone().into(),
+		value,
+	})
+}
+
+#[test]
+fn batch_all_is_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, charlie] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+
+		assert_ok!(Utility::batch_all(
+			RuntimeOrigin::signed(alice.clone()),
+			vec![transfer(&bob, 100), transfer(&charlie, 200)],
+		));
+		assert_eq!(Balances::free_balance(&bob), INITIAL_BALANCE + 100);
+		assert_eq!(Balances::free_balance(&charlie), INITIAL_BALANCE + 200);
+
+		// The second transfer cannot be afforded, so the first one is rolled back as well.
+		assert_err_ignore_postinfo!(
+			Utility::batch_all(
+				RuntimeOrigin::signed(alice),
+				vec![transfer(&bob, 100), transfer(&charlie, INITIAL_BALANCE)],
+			),
+			TokenError::FundsUnavailable
+		);
+		assert_eq!(Balances::free_balance(&bob), INITIAL_BALANCE + 100);
+	});
+}
+
+#[test]
+fn batch_stops_at_the_first_failure() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, charlie] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+
+		assert_ok!(Utility::batch(
+			RuntimeOrigin::signed(alice),
+			vec![transfer(&bob, 100), transfer(&charlie, INITIAL_BALANCE), transfer(&charlie, 1)],
+		));
+		assert_eq!(Balances::free_balance(&bob), INITIAL_BALANCE + 100);
+		assert_eq!(Balances::free_balance(&charlie), INITIAL_BALANCE);
+		assert!(System::events().iter().any(|r| matches!(
+			r.event,
+			RuntimeEvent::Utility(pallet_utility::Event::BatchInterrupted { index: 1, .. })
+		)));
+	});
+}
+
+#[test]
+fn force_batch_carries_on_after_failures() {
+	new_test_ext().execute_with(|| {
+		let [alice, bob, charlie] = AUTHORITIES.map(|(sr, _)| sr.to_account_id());
+
+		assert_ok!(Utility::force_batch(
+			RuntimeOrigin::signed(alice),
+			vec![transfer(&bob, 100), transfer(&charlie, INITIAL_BALANCE), transfer(&charlie, 1)],
+		));
+		assert_eq!(Balances::free_balance(&bob), INITIAL_BALANCE + 100);
+		assert_eq!(Balances::free_balance(&charlie), INITIAL_BALANCE + 1);
+		System::assert_last_event(pallet_utility::Event::BatchCompletedWithErrors.into());
+	});
+}
@@ -0,0 +1,107 @@
+use super::*;
+use frame_support::{
+	assert_ok,
+	traits::{tokens::Precision, OnRuntimeUpgrade},
+};
+
+#[test]
+fn added_validator_becomes_aura_and_grandpa_authority() {
+	new_test_ext().execute_with(|| {
+		let (sr, ed) = (Sr25519Keyring::Dave, Ed25519Keyring::Dave);
+		let who = sr.to_account_id();
+		// Registering session keys takes a consumer reference, so the account must exist.
+		Balances::make_free_balance_be(&who, INITIAL_BALANCE);
+		let keys = opaque::SessionKeys { aura: sr.public().into(), grandpa: ed.public().into() };
+		assert_ok!(Session::set_keys(RuntimeOrigin::signed(who.clone()), keys, Vec::new()));
+		assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), who.clone()));
+
+		// The new set is only planned at the next boundary...
+		start_session(1);
+		assert!(!Aura::authorities().contains(&AuraId::from(sr.public())));
+
+		// ...and both consensus engines switch to it at the one after.
+		start_session(2);
+		assert!(Aura::authorities().contains(&AuraId::from(sr.public())));
+		assert!(Grandpa::grandpa_authorities()
+			.iter()
+			.any(|(id, _)| id == &GrandpaId::from(ed.public())));
+	});
+}
+
+#[test]
+fn removed_validator_stops_authoring() {
+	new_test_ext().execute_with(|| {
+		let (sr, ed) = AUTHORITIES[2];
+		assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), sr.to_account_id()));
+
+		start_session(2);
+		assert_eq!(Aura::authorities().len(), AUTHORITIES.len() - 1);
+		assert!(!Aura::authorities().contains(&AuraId::from(sr.public())));
+		assert!(!Grandpa::grandpa_authorities()
+			.iter()
+			.any(|(id, _)| id == &GrandpaId::from(ed.public())));
+	});
+}
+
+#[test]
+fn only_root_manages_validators() {
+	new_test_ext().execute_with(|| {
+		let who = AUTHORITIES[0].0.to_account_id();
+		assert!(ValidatorSet::remove_validator(RuntimeOrigin::signed(who.clone()), who).is_err());
+	});
+}
+
+/// Remove what `Session` and `ValidatorSet` store about the validators, leaving the Aura and
+/// GRANDPA authorities alone, as on a chain started before both pallets were added.
+fn forget_session() {
+	for (sr, _) in AUTHORITIES {
+		let who = sr.to_account_id();
+		assert_ok!(Session::purge_keys(RuntimeOrigin::signed(who.clone())));
+		assert_ok!(Balances::release_all(
+			&pallet_validator_set::HoldReason::ValidatorBond.into(),
+			&who,
+			Precision::Exact,
+		));
+	}
+	pallet_session::Validators::<Runtime>::kill();
+	pallet_session::QueuedKeys::<Runtime>::kill();
+	pallet_validator_set::Validators::<Runtime>::kill();
+}
+
+#[test]
+fn initialize_session_hands_the_authorities_over_to_the_session_pallet() {
+	new_test_ext().execute_with(|| {
+		forget_session();
+		let aura = Aura::authorities();
+		let grandpa = Grandpa::grandpa_authorities();
+
+		InitializeSession::on_runtime_upgrade();
+
+		let validators: Vec<_> = AUTHORITIES.iter().map(|(sr, _)| sr.to_account_id()).collect();
+		assert_eq!(Session::validators(), validators);
+		assert_eq!(pallet_validator_set::Validators::<Runtime>::get().into_inner(), validators);
+		let reason = pallet_validator_set::HoldReason::ValidatorBond.into();
+		for who in &validators {
+			assert_eq!(Balances::balance_on_hold(&reason, who), ValidatorBond::get());
+		}
+
+		// The authorities survive the session rotations.
+		start_session(3);
+		assert_eq!(Aura::authorities(), aura);
+		assert_eq!(Grandpa::grandpa_authorities(), grandpa);
+	});
+}
+
+#[test]
+fn initialize_session_leaves_a_chain_with_session_alone() {
+	new_test_ext().execute_with(|| {
+		let (sr, _) = AUTHORITIES[2];
+		assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), sr.to_account_id()));
+
+		InitializeSession::on_runtime_upgrade();
+
+		assert_eq!(pallet_validator_set::Validators::<Runtime>::get().len(), AUTHORITIES.len() - 1);
+		let reason = pallet_validator_set::HoldReason::ValidatorBond.into();
+		assert_eq!(Balances::balance_on_hold(&reason, &sr.to_account_id()), 0);
+	});
+}
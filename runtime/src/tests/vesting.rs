@@ -0,0 +1,72 @@
+use super::*;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::Inspect, tokens::Preservation, VestingSchedule},
+};
+use sp_runtime::TokenError;
+
+const ALLOCATION: Balance = 1_000 * EXISTENTIAL_DEPOSIT;
+const LIQUID: Balance = 100 * EXISTENTIAL_DEPOSIT;
+
+/// A chain where Dave gets [`ALLOCATION`], of which all but [`LIQUID`] vests over 100 blocks.
+fn vesting_ext() -> sp_io::TestExternalities {
+	let dave = Sr25519Keyring::Dave.to_account_id();
+	let mut genesis = test_genesis();
+	genesis.balances.balances.push((dave.clone(), ALLOCATION));
+	genesis.vesting.vesting = vec![(dave, 0, 100, LIQUID)];
+	new_test_ext_with(genesis)
+}
+
+#[test]
+fn genesis_allocation_is_locked_but_the_liquid_portion() {
+	vesting_ext().execute_with(|| {
+		let dave = Sr25519Keyring::Dave.to_account_id();
+		let bob = Sr25519Keyring::Bob.to_account_id();
+		System::set_block_number(0);
+
+		assert_eq!(Vesting::vesting_balance(&dave), Some(ALLOCATION - LIQUID));
+		assert_eq!(
+			Balances::reducible_balance(&dave, Preservation::Expendable, Default::default()),
+			LIQUID
+		);
+		assert_noop!(
+			Balances::transfer_allow_death(
+				RuntimeOrigin::signed(dave.clone()),
+				bob.clone().into(),
+				LIQUID + 1
+			),
+			TokenError::Frozen
+		);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(dave), bob.into(), LIQUID));
+	});
+}
+
+#[test]
+fn genesis_allocation_unlocks_linearly() {
+	vesting_ext().execute_with(|| {
+		let dave = Sr25519Keyring::Dave.to_account_id();
+		let bob = Sr25519Keyring::Bob.to_account_id();
+		let locked = ALLOCATION - LIQUID;
+
+		System::set_block_number(50);
+		assert_eq!(Vesting::vesting_balance(&dave), Some(locked / 2));
+		assert_ok!(Vesting::vest(RuntimeOrigin::signed(dave.clone())));
+		assert_noop!(
+			Balances::transfer_allow_death(
+				RuntimeOrigin::signed(dave.clone()),
+				bob.clone().into(),
+				ALLOCATION - locked / 2 + 1
+			),
+			TokenError::Frozen
+		);
+
+		System::set_block_number(100);
+		assert_ok!(Vesting::vest(RuntimeOrigin::signed(dave.clone())));
+		assert_eq!(Vesting::vesting_balance(&dave), None);
+		assert_ok!(Balances::transfer_allow_death(
+			RuntimeOrigin::signed(dave),
+			bob.into(),
+			ALLOCATION
+		));
+	});
+}